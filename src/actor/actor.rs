@@ -1,5 +1,7 @@
 use crate::message::actor_stop_message::ActorStopMessage;
-use crate::prelude::{ActorContext, ActorPanicSource, ActorResult, SerializedMessage};
+use crate::prelude::{
+    ActorContext, ActorPanicSource, ActorResult, ActorTerminated, SerializedMessage,
+};
 use log::error;
 use std::error::Error;
 use std::panic::UnwindSafe;
@@ -106,7 +108,7 @@ pub trait Actor: Send + Sync + UnwindSafe + Sized {
     /// executed whenever a panic occurs within the actor
     ///
     /// determines actor behavior in case of a panic based on return value
-    /// WARNING: returning `ActorResult::Restart` if `source == ActorPanicSource::Restart` can potentially result in an endless loop that will block the thread the actor is running on, until the restart was successful
+    /// WARNING: returning `ActorResult::Restart` if `source == ActorPanicSource::Restart` can potentially result in an endless loop, until the restart was successful. Use [ActorBuilder.set_restart_policy](../prelude/struct.ActorBuilder.html#method.set_restart_policy) to delay and limit restarts
     /// NOTE: if this function panics it will trigger a second time with `source == ActorPanicSource::OnPanic`, if another panic occurs in that case, the actor will be stopped immediately without finishing the mailbox!
     ///
     /// # Examples
@@ -452,4 +454,69 @@ pub trait Actor: Send + Sync + UnwindSafe + Sized {
         }
         return Ok(ActorResult::Ok);
     }
+
+    /// executed when Actor handles internal ActorTerminated message, which is sent when an actor that is watched through [ActorContext.watch](../prelude/struct.ActorContext.html#method.watch) terminates
+    ///
+    /// Default behavior does nothing
+    /// panic triggers `self.on_panic()` with `source = ActorPanicSource::Message`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tyra::prelude::*;
+    /// use std::error::Error;
+    /// use std::time::Duration;
+    ///
+    /// struct TargetActor {}
+    /// impl Actor for TargetActor {}
+    ///
+    /// struct TargetActorFactory {}
+    /// impl ActorFactory<TargetActor> for TargetActorFactory {
+    ///     fn new_actor(&mut self, _context: ActorContext<TargetActor>) -> Result<TargetActor, Box<dyn Error>> {
+    ///         Ok(TargetActor {})
+    ///     }
+    /// }
+    ///
+    /// struct TestActor {}
+    /// impl Actor for TestActor {
+    ///     fn on_actor_terminated(&mut self, context: &ActorContext<Self>, msg: ActorTerminated) -> Result<ActorResult, Box<dyn Error>> {
+    ///         assert_eq!("target", msg.address.actor);
+    ///         assert_eq!(TerminationReason::Stopped, msg.reason);
+    ///         context.system.stop(Duration::from_millis(1000));
+    ///         return Ok(ActorResult::Ok);
+    ///     }
+    /// }
+    ///
+    /// struct TestActorFactory {}
+    /// impl ActorFactory<TestActor> for TestActorFactory {
+    ///     fn new_actor(&mut self, _context: ActorContext<TestActor>) -> Result<TestActor, Box<dyn Error>> {
+    ///         Ok(TestActor {})
+    ///     }
+    /// }
+    ///
+    /// impl Handler<ActorInitMessage> for TestActor {
+    ///     fn handle(&mut self, _msg: ActorInitMessage, context: &ActorContext<Self>) -> Result<ActorResult, Box<dyn Error>> {
+    ///         let target = context.system.builder().spawn("target", TargetActorFactory {})?;
+    ///         context.watch(&target);
+    ///         target.stop()?;
+    ///         return Ok(ActorResult::Ok);
+    ///     }
+    /// }
+    ///
+    /// #[ntest::timeout(10000)]
+    /// fn main() {
+    ///     let actor_config = TyraConfig::new().unwrap();
+    ///     let actor_system = ActorSystem::new(actor_config);
+    ///     let actor = actor_system.builder().spawn("test", TestActorFactory {}).unwrap();
+    ///     actor.send(ActorInitMessage::new()).unwrap();
+    ///     std::process::exit(actor_system.await_shutdown());
+    /// }
+    /// ```
+    fn on_actor_terminated(
+        &mut self,
+        _context: &ActorContext<Self>,
+        _msg: ActorTerminated,
+    ) -> Result<ActorResult, Box<dyn Error>> {
+        return Ok(ActorResult::Ok);
+    }
 }
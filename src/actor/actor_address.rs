@@ -1,7 +1,118 @@
-#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Clone)]
+use crate::actor::actor_address_parse_error::ActorAddressParseError;
+use serde::de::Error;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+const ADDRESS_SCHEME: &str = "tyra://";
+/// characters that are reserved by the canonical form of the address
+const RESERVED_CHARACTERS: [char; 5] = ['/', '@', ':', '?', '#'];
+
+/// Unique address of an actor
+///
+/// The canonical form of an address is `tyra://system@remote/pool/actor`, which is used by [Display](#impl-Display-for-ActorAddress), [FromStr](#impl-FromStr-for-ActorAddress) and serde
+///
+/// # Examples
+///
+/// ```rust
+/// use tyra::prelude::ActorAddress;
+/// use std::str::FromStr;
+///
+/// let address = ActorAddress::from_str("tyra://hello@local/default/world").unwrap();
+/// assert_eq!(address.system, "hello");
+/// assert_eq!(address.remote, "local");
+/// assert_eq!(address.pool, "default");
+/// assert_eq!(address.actor, "world");
+/// assert_eq!(address.to_string(), "tyra://hello@local/default/world");
+///
+/// let serialized = bincode::serialize(&address).unwrap();
+/// let deserialized: ActorAddress = bincode::deserialize(&serialized).unwrap();
+/// assert_eq!(address, deserialized);
+///
+/// assert!(ActorAddress::from_str("hello@local/default/world").is_err());
+/// assert!(ActorAddress::from_str("tyra://hello@local/default/wor/ld").is_err());
+/// ```
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Debug)]
 pub struct ActorAddress {
     pub remote: String,
     pub system: String,
     pub pool: String,
     pub actor: String,
 }
+
+impl ActorAddress {
+    /// Returns `true` if `name` can be used as part of an address
+    ///
+    /// Names must not be empty and must not contain whitespaces, control characters or any of `/`, `@`, `:`, `?` and `#`
+    pub fn is_valid_name(name: &str) -> bool {
+        if name.is_empty() {
+            return false;
+        }
+        return !name
+            .chars()
+            .any(|c| c.is_whitespace() || c.is_control() || RESERVED_CHARACTERS.contains(&c));
+    }
+
+    fn parse_name(name: &str) -> Result<String, ActorAddressParseError> {
+        if !Self::is_valid_name(name) {
+            return Err(ActorAddressParseError::InvalidNameError {
+                name: String::from(name),
+            });
+        }
+        return Ok(String::from(name));
+    }
+}
+
+impl Display for ActorAddress {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}{}@{}/{}/{}",
+            ADDRESS_SCHEME, self.system, self.remote, self.pool, self.actor
+        )
+    }
+}
+
+impl FromStr for ActorAddress {
+    type Err = ActorAddressParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let address = match s.strip_prefix(ADDRESS_SCHEME) {
+            Some(address) => address,
+            None => return Err(ActorAddressParseError::InvalidSchemeError),
+        };
+        let (system, path) = match address.split_once('@') {
+            Some(parts) => parts,
+            None => return Err(ActorAddressParseError::InvalidFormatError),
+        };
+        let path: Vec<&str> = path.split('/').collect();
+        if path.len() != 3 {
+            return Err(ActorAddressParseError::InvalidFormatError);
+        }
+        return Ok(Self {
+            system: Self::parse_name(system)?,
+            remote: Self::parse_name(path[0])?,
+            pool: Self::parse_name(path[1])?,
+            actor: Self::parse_name(path[2])?,
+        });
+    }
+}
+
+impl Serialize for ActorAddress {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        return serializer.serialize_str(&self.to_string());
+    }
+}
+
+impl<'de> Deserialize<'de> for ActorAddress {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let address = String::deserialize(deserializer)?;
+        return ActorAddress::from_str(&address).map_err(D::Error::custom);
+    }
+}
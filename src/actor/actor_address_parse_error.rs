@@ -0,0 +1,16 @@
+use thiserror::Error;
+
+#[derive(Error, Debug, PartialEq)]
+pub enum ActorAddressParseError {
+    /// Triggered by [ActorAddress::from_str](../prelude/struct.ActorAddress.html#method.from_str) if the address does not start with `tyra://`
+    #[error("Actor address has to start with tyra://")]
+    InvalidSchemeError,
+
+    /// Triggered by [ActorAddress::from_str](../prelude/struct.ActorAddress.html#method.from_str) if the address does not match `tyra://system@remote/pool/actor`
+    #[error("Actor address has to match tyra://system@remote/pool/actor")]
+    InvalidFormatError,
+
+    /// Triggered by [ActorAddress::from_str](../prelude/struct.ActorAddress.html#method.from_str) if a part of the address is empty or contains illegal characters
+    #[error("Actor address contains an invalid name {}", .name)]
+    InvalidNameError { name: String },
+}
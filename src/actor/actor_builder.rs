@@ -4,6 +4,11 @@ use crate::actor::actor_factory::ActorFactory;
 use crate::actor::actor_wrapper::ActorWrapper;
 use crate::actor::executor::{Executor, ExecutorTrait};
 use crate::actor::mailbox::Mailbox;
+use crate::actor::mailbox_overflow_strategy::MailboxOverflowStrategy;
+use crate::actor::restart_policy::RestartPolicy;
+use crate::actor::supervision_strategy::SupervisionStrategy;
+use crate::actor::termination_handle::TerminationHandle;
+use crate::config::actor_deployment_config::ActorDeploymentConfig;
 use crate::config::tyra_config::DEFAULT_POOL;
 use crate::prelude::{Actor, Handler, SerializedMessage};
 use crate::system::actor_error::ActorError;
@@ -12,8 +17,8 @@ use crate::system::internal_actor_manager::InternalActorManager;
 use crate::system::system_state::SystemState;
 use crate::system::wakeup_manager::WakeupManager;
 use dashmap::DashMap;
-use std::sync::atomic::AtomicBool;
-use std::sync::{Arc, RwLock};
+use std::sync::atomic::{AtomicBool, AtomicUsize};
+use std::sync::{Arc, Mutex, RwLock};
 
 /// Used to create [Actor]s in the [ActorSystem]
 ///
@@ -31,6 +36,9 @@ where
     wakeup_manager: WakeupManager,
     internal_actor_manager: InternalActorManager,
     actor_config: ActorConfig,
+    /// applied after the `[actors]` section of the config
+    forced_config: ActorDeploymentConfig,
+    parent: Option<ActorAddress>,
 }
 
 impl<A> ActorBuilder<A>
@@ -49,7 +57,13 @@ where
         let actor_config = ActorConfig {
             pool_name: String::from(DEFAULT_POOL),
             mailbox_size: config.general.default_mailbox_size,
+            mailbox_overflow_strategy: MailboxOverflowStrategy::default(),
             message_throughput: config.general.default_message_throughput,
+            sequential_async_handling: false,
+            priority_mailbox: false,
+            supervision_strategy: SupervisionStrategy::OneForOne,
+            restart_policy: RestartPolicy::default(),
+            shutdown_phase: 0,
         };
 
         ActorBuilder {
@@ -59,6 +73,8 @@ where
             wakeup_manager,
             internal_actor_manager,
             actor_config,
+            forced_config: ActorDeploymentConfig::default(),
+            parent: None,
         }
     }
 
@@ -81,6 +97,379 @@ where
         self
     }
 
+    /// Defines what happens to messages that are sent while the bounded mailbox is full, see [MailboxOverflowStrategy](../prelude/enum.MailboxOverflowStrategy.html)
+    ///
+    /// Per default the sender blocks until there's space in the mailbox
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tyra::prelude::*;
+    /// use std::error::Error;
+    /// use std::sync::{Arc, Barrier};
+    /// use std::time::Duration;
+    ///
+    /// struct TestActor {}
+    /// impl Actor for TestActor {}
+    ///
+    /// struct TestActorFactory {}
+    /// impl ActorFactory<TestActor> for TestActorFactory {
+    ///     fn new_actor(&mut self, _context: ActorContext<TestActor>) -> Result<TestActor, Box<dyn Error>> {
+    ///         Ok(TestActor {})
+    ///     }
+    /// }
+    ///
+    /// struct Pause {
+    ///     started: Arc<Barrier>,
+    ///     resume: Arc<Barrier>,
+    /// }
+    /// impl ActorMessage for Pause {}
+    ///
+    /// struct FooBar {}
+    /// impl ActorMessage for FooBar {}
+    ///
+    /// impl Handler<Pause> for TestActor {
+    ///     fn handle(&mut self, msg: Pause, _context: &ActorContext<Self>) -> Result<ActorResult, Box<dyn Error>> {
+    ///         msg.started.wait();
+    ///         msg.resume.wait();
+    ///         Ok(ActorResult::Ok)
+    ///     }
+    /// }
+    ///
+    /// impl Handler<FooBar> for TestActor {
+    ///     fn handle(&mut self, _msg: FooBar, context: &ActorContext<Self>) -> Result<ActorResult, Box<dyn Error>> {
+    ///         context.system.stop(Duration::from_millis(1000));
+    ///         Ok(ActorResult::Ok)
+    ///     }
+    /// }
+    ///
+    /// #[ntest::timeout(10000)]
+    /// fn main() {
+    ///     let actor_config = TyraConfig::new().unwrap();
+    ///     let actor_system = ActorSystem::new(actor_config);
+    ///     let actor = actor_system
+    ///         .builder()
+    ///         .set_mailbox_size(1)
+    ///         .set_mailbox_overflow_strategy(MailboxOverflowStrategy::Reject)
+    ///         .spawn("test", TestActorFactory {})
+    ///         .unwrap();
+    ///
+    ///     let started = Arc::new(Barrier::new(2));
+    ///     let resume = Arc::new(Barrier::new(2));
+    ///     actor.send(Pause { started: started.clone(), resume: resume.clone() }).unwrap();
+    ///     // the actor is blocked in the handler and the mailbox is empty
+    ///     started.wait();
+    ///     actor.send(FooBar {}).unwrap();
+    ///     let result = actor.send(FooBar {});
+    ///     assert_eq!(result, Err(ActorSendError::MailboxFullError));
+    ///     resume.wait();
+    ///
+    ///     std::process::exit(actor_system.await_shutdown());
+    /// }
+    /// ```
+    pub fn set_mailbox_overflow_strategy(
+        mut self,
+        mailbox_overflow_strategy: MailboxOverflowStrategy,
+    ) -> ActorBuilder<A> {
+        self.actor_config.mailbox_overflow_strategy = mailbox_overflow_strategy;
+        self
+    }
+
+    /// Defines if messages are handled in the order of their [priority](../prelude/trait.ActorMessage.html#method.get_priority) instead of the order they were sent
+    ///
+    /// Per default messages are handled in the order they were sent
+    /// Internal control messages, like stopping the actor, are always handled before any other message, regardless of this setting
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tyra::prelude::*;
+    /// use std::error::Error;
+    /// use std::time::Duration;
+    ///
+    /// struct TestActor {
+    ///     handled: Vec<usize>,
+    /// }
+    /// impl Actor for TestActor {}
+    ///
+    /// struct TestActorFactory {}
+    /// impl ActorFactory<TestActor> for TestActorFactory {
+    ///     fn new_actor(&mut self, _context: ActorContext<TestActor>) -> Result<TestActor, Box<dyn Error>> {
+    ///         Ok(TestActor { handled: Vec::new() })
+    ///     }
+    /// }
+    ///
+    /// struct Pause {}
+    /// impl ActorMessage for Pause {}
+    ///
+    /// struct Job {
+    ///     priority: usize,
+    /// }
+    /// impl ActorMessage for Job {
+    ///     fn get_priority(&self) -> usize {
+    ///         self.priority
+    ///     }
+    /// }
+    ///
+    /// impl Handler<Pause> for TestActor {
+    ///     fn handle(&mut self, _msg: Pause, _context: &ActorContext<Self>) -> Result<ActorResult, Box<dyn Error>> {
+    ///         Ok(ActorResult::Sleep(Duration::from_millis(500)))
+    ///     }
+    /// }
+    ///
+    /// impl Handler<Job> for TestActor {
+    ///     fn handle(&mut self, msg: Job, context: &ActorContext<Self>) -> Result<ActorResult, Box<dyn Error>> {
+    ///         self.handled.push(msg.priority);
+    ///         if self.handled.len() == 3 {
+    ///             assert_eq!(self.handled, vec![10, 5, 0]);
+    ///             context.system.stop(Duration::from_millis(1000));
+    ///         }
+    ///         Ok(ActorResult::Ok)
+    ///     }
+    /// }
+    ///
+    /// #[ntest::timeout(10000)]
+    /// fn main() {
+    ///     let actor_config = TyraConfig::new().unwrap();
+    ///     let actor_system = ActorSystem::new(actor_config);
+    ///     let actor = actor_system
+    ///         .builder()
+    ///         .set_priority_mailbox(true)
+    ///         .spawn("test", TestActorFactory {})
+    ///         .unwrap();
+    ///
+    ///     // the actor sleeps while the jobs are queued
+    ///     actor.send(Pause {}).unwrap();
+    ///     actor.send(Job { priority: 0 }).unwrap();
+    ///     actor.send(Job { priority: 10 }).unwrap();
+    ///     actor.send(Job { priority: 5 }).unwrap();
+    ///
+    ///     std::process::exit(actor_system.await_shutdown());
+    /// }
+    /// ```
+    pub fn set_priority_mailbox(mut self, priority_mailbox: bool) -> ActorBuilder<A> {
+        self.actor_config.priority_mailbox = priority_mailbox;
+        self
+    }
+
+    /// Defines if the next message is only processed after the future of the previous [AsyncHandler](../prelude/trait.AsyncHandler.html) completed
+    ///
+    /// Per default messages continue to be processed while futures are pending
+    pub fn set_sequential_async_handling(
+        mut self,
+        sequential_async_handling: bool,
+    ) -> ActorBuilder<A> {
+        self.actor_config.sequential_async_handling = sequential_async_handling;
+        self
+    }
+
+    /// Defines in which phase the actor is stopped during [ActorSystem.stop](../prelude/struct.ActorSystem.html#method.stop)
+    ///
+    /// Phases are stopped in ascending order, the next phase is only stopped once all actors of the previous phase stopped or were force stopped after the `graceful_termination_timeout`
+    /// Per default all actors are stopped in phase `0`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::error::Error;
+    /// use std::sync::{Arc, Mutex};
+    /// use std::time::Duration;
+    /// use tyra::prelude::*;
+    ///
+    /// struct Storage {}
+    /// impl Actor for Storage {}
+    ///
+    /// struct StorageFactory {}
+    /// impl ActorFactory<Storage> for StorageFactory {
+    ///     fn new_actor(&mut self, _context: ActorContext<Storage>) -> Result<Storage, Box<dyn Error>> {
+    ///         Ok(Storage {})
+    ///     }
+    /// }
+    ///
+    /// struct Ingress {
+    ///     storage: ActorWrapper<Storage>,
+    ///     storage_state: Arc<Mutex<Option<ActorState>>>,
+    /// }
+    /// impl Actor for Ingress {
+    ///     fn post_stop(&mut self, _context: &ActorContext<Self>) {
+    ///         *self.storage_state.lock().unwrap() = Some(self.storage.get_state());
+    ///     }
+    /// }
+    ///
+    /// struct IngressFactory {
+    ///     storage: ActorWrapper<Storage>,
+    ///     storage_state: Arc<Mutex<Option<ActorState>>>,
+    /// }
+    /// impl ActorFactory<Ingress> for IngressFactory {
+    ///     fn new_actor(&mut self, _context: ActorContext<Ingress>) -> Result<Ingress, Box<dyn Error>> {
+    ///         Ok(Ingress { storage: self.storage.clone(), storage_state: self.storage_state.clone() })
+    ///     }
+    /// }
+    ///
+    /// #[ntest::timeout(10000)]
+    /// fn main() {
+    ///     let actor_config = TyraConfig::new().unwrap();
+    ///     let actor_system = ActorSystem::new(actor_config);
+    ///     let storage = actor_system
+    ///         .builder()
+    ///         .set_shutdown_phase(1)
+    ///         .spawn("storage", StorageFactory {})
+    ///         .unwrap();
+    ///     let storage_state = Arc::new(Mutex::new(None));
+    ///     actor_system
+    ///         .builder()
+    ///         .spawn("ingress", IngressFactory { storage, storage_state: storage_state.clone() })
+    ///         .unwrap();
+    ///
+    ///     actor_system.stop(Duration::from_secs(1));
+    ///     assert_eq!(actor_system.await_shutdown(), 0);
+    ///     assert!(actor_system.get_force_stopped_actors().is_empty());
+    ///     // the storage is stopped in a later phase and was still running once the ingress stopped
+    ///     let storage_state = storage_state.lock().unwrap().unwrap();
+    ///     assert_ne!(storage_state, ActorState::Stopped);
+    /// }
+    /// ```
+    pub fn set_shutdown_phase(mut self, shutdown_phase: usize) -> ActorBuilder<A> {
+        self.actor_config.shutdown_phase = shutdown_phase;
+        self
+    }
+
+    /// Defines how the actor reacts to failures of its children, see [SupervisionStrategy](../prelude/enum.SupervisionStrategy.html)
+    ///
+    /// Per default only the failing child is affected
+    pub fn set_supervision_strategy(
+        mut self,
+        supervision_strategy: SupervisionStrategy,
+    ) -> ActorBuilder<A> {
+        self.actor_config.supervision_strategy = supervision_strategy;
+        self
+    }
+
+    /// Defines the backoff between restarts and how many restarts are allowed within a time window, see [RestartPolicy](../prelude/struct.RestartPolicy.html)
+    ///
+    /// Per default actors are restarted immediately and without any limit
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tyra::prelude::*;
+    /// use std::error::Error;
+    /// use std::sync::Arc;
+    /// use std::sync::atomic::{AtomicUsize, Ordering};
+    /// use std::time::Duration;
+    ///
+    /// struct TestActor {}
+    /// impl Actor for TestActor {}
+    ///
+    /// struct TestActorFactory {
+    ///     created: Arc<AtomicUsize>,
+    /// }
+    /// impl ActorFactory<TestActor> for TestActorFactory {
+    ///     fn new_actor(&mut self, _context: ActorContext<TestActor>) -> Result<TestActor, Box<dyn Error>> {
+    ///         self.created.fetch_add(1, Ordering::Relaxed);
+    ///         Ok(TestActor {})
+    ///     }
+    /// }
+    ///
+    /// struct Fail {}
+    /// impl ActorMessage for Fail {}
+    ///
+    /// impl Handler<Fail> for TestActor {
+    ///     fn handle(&mut self, _msg: Fail, _context: &ActorContext<Self>) -> Result<ActorResult, Box<dyn Error>> {
+    ///         panic!("restart me");
+    ///     }
+    /// }
+    ///
+    /// #[ntest::timeout(10000)]
+    /// fn main() {
+    ///     let actor_config = TyraConfig::new().unwrap();
+    ///     let actor_system = ActorSystem::new(actor_config);
+    ///     let created = Arc::new(AtomicUsize::new(0));
+    ///     let policy = RestartPolicy::new(2, Duration::from_secs(60), Duration::from_millis(10), Duration::from_millis(100));
+    ///     let actor = actor_system
+    ///         .builder()
+    ///         .set_restart_policy(policy)
+    ///         .spawn("test", TestActorFactory { created: created.clone() })
+    ///         .unwrap();
+    ///
+    ///     while actor.send(Fail {}).is_ok() {
+    ///         std::thread::sleep(Duration::from_millis(100));
+    ///     }
+    ///     // initial creation and 2 restarts
+    ///     assert_eq!(3, created.load(Ordering::Relaxed));
+    ///
+    ///     actor_system.stop(Duration::from_millis(1000));
+    ///     std::process::exit(actor_system.await_shutdown());
+    /// }
+    /// ```
+    pub fn set_restart_policy(mut self, restart_policy: RestartPolicy) -> ActorBuilder<A> {
+        self.actor_config.restart_policy = restart_policy;
+        self
+    }
+
+    /// Settings of the builder are defaults that can be overwritten through the `[actors]` section of the config, see [ActorDeploymentConfig](../prelude/struct.ActorDeploymentConfig.html)
+    ///
+    /// All settings that are defined in `forced_config` are applied after the config and can therefore not be overwritten
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tyra::prelude::*;
+    /// use std::error::Error;
+    /// use std::str::FromStr;
+    /// use std::time::Duration;
+    ///
+    /// struct TestActor {}
+    /// impl Actor for TestActor {}
+    ///
+    /// struct TestActorFactory {}
+    /// impl ActorFactory<TestActor> for TestActorFactory {
+    ///     fn new_actor(&mut self, _context: ActorContext<TestActor>) -> Result<TestActor, Box<dyn Error>> {
+    ///         Ok(TestActor {})
+    ///     }
+    /// }
+    ///
+    /// #[ntest::timeout(10000)]
+    /// fn main() {
+    ///     let actor_config = TyraConfig::from_str(r#"
+    ///     [actors."default/worker-*"]
+    ///     pool_name = "workers"
+    ///     mailbox_size = 2
+    ///     "#).unwrap();
+    ///     let actor_system = ActorSystem::new(actor_config);
+    ///     actor_system.add_pool("workers").unwrap();
+    ///
+    ///     // the config moves the actor to the `workers` pool
+    ///     let worker = actor_system.builder().spawn("worker-1", TestActorFactory {}).unwrap();
+    ///     assert_eq!(worker.get_address().pool, "workers");
+    ///
+    ///     // the pool is forced through the code and can't be overwritten by the config
+    ///     let forced_config = ActorDeploymentConfig {
+    ///         pool_name: Some(String::from("default")),
+    ///         ..Default::default()
+    ///     };
+    ///     let worker = actor_system
+    ///         .builder()
+    ///         .set_forced_config(forced_config)
+    ///         .spawn("worker-2", TestActorFactory {})
+    ///         .unwrap();
+    ///     assert_eq!(worker.get_address().pool, "default");
+    ///
+    ///     actor_system.stop(Duration::from_millis(1000));
+    ///     std::process::exit(actor_system.await_shutdown());
+    /// }
+    /// ```
+    pub fn set_forced_config(mut self, forced_config: ActorDeploymentConfig) -> ActorBuilder<A> {
+        self.forced_config = forced_config;
+        self
+    }
+
+    /// Used by [ActorContext.child_builder](../prelude/struct.ActorContext.html#method.child_builder)
+    pub(crate) fn set_parent(mut self, parent: ActorAddress) -> ActorBuilder<A> {
+        self.parent = Some(parent);
+        self
+    }
+
     /// Creates the defined [Actor] on the [ActorSystem]
     ///
     /// # Returns
@@ -178,6 +567,10 @@ where
     ///     let err = this_is_not_working_either.err().unwrap();
     ///     assert_eq!(err, ActorError::InvalidActorTypeError, "Error is not correct");
     ///
+    ///     //this does not work, because the name contains characters that are reserved for the address
+    ///     let invalid_name = actor_system.builder().spawn("in/valid", TestActorFactory::new());
+    ///     assert_eq!(invalid_name.err().unwrap(), ActorError::InvalidActorNameError { name: String::from("in/valid") });
+    ///
     ///     actor_system.stop(Duration::from_millis(1000));
     ///     std::process::exit(actor_system.await_shutdown());
     /// }
@@ -186,10 +579,21 @@ where
     where
         P: ActorFactory<A> + 'static,
     {
+        let name = name.into();
+        if !ActorAddress::is_valid_name(&name) {
+            return Err(ActorError::InvalidActorNameError { name });
+        }
+        let mut actor_config = self.actor_config.clone();
+        self.system
+            .get_config()
+            .get_actor_deployment_config(&actor_config.pool_name, &name)
+            .apply(&mut actor_config);
+        self.forced_config.apply(&mut actor_config);
+
         let actor_address = ActorAddress {
-            actor: name.into(),
+            actor: name,
             system: String::from(self.system.get_name()),
-            pool: self.actor_config.pool_name.clone(),
+            pool: actor_config.pool_name.clone(),
             remote: String::from("local"),
         };
 
@@ -199,16 +603,42 @@ where
                 .get_actor_ref(actor_address, self.internal_actor_manager.clone());
         }
 
-        let (sender, receiver) = if self.actor_config.mailbox_size == 0 {
+        // the executor moves messages of priority mailboxes out of the channel, which is why their capacity is tracked separately
+        let is_capacity_tracked = actor_config.priority_mailbox && actor_config.mailbox_size != 0;
+        let (sender, receiver) = if actor_config.mailbox_size == 0 || is_capacity_tracked {
             flume::unbounded()
         } else {
-            flume::bounded(self.actor_config.mailbox_size)
+            flume::bounded(actor_config.mailbox_size)
         };
+        let (capacity_sender, capacity_receiver) = if is_capacity_tracked {
+            let (capacity_sender, capacity_receiver) = flume::bounded(actor_config.mailbox_size);
+            (Some(capacity_sender), Some(capacity_receiver))
+        } else {
+            (None, None)
+        };
+
+        let (control_sender, control_receiver) = flume::unbounded();
 
         let mailbox = Mailbox {
             is_stopped: Arc::new(AtomicBool::new(false)),
             is_sleeping: Arc::new(AtomicBool::new(true)),
+            sleeping_until: Arc::new(Mutex::new(None)),
             msg_in: sender,
+            capacity_in: capacity_sender,
+            control_in: control_sender,
+            overflow_strategy: actor_config.mailbox_overflow_strategy,
+            msg_out: if actor_config.mailbox_overflow_strategy
+                == MailboxOverflowStrategy::DropOldest
+            {
+                Some(receiver.clone())
+            } else {
+                None
+            },
+            termination_handle: TerminationHandle::new(),
+            message_throughput: Arc::new(AtomicUsize::new(actor_config.message_throughput)),
+            prioritized_len: Arc::new(AtomicUsize::new(0)),
+            address: actor_address.clone(),
+            internal_actor_manager: Box::new(self.internal_actor_manager.clone()),
         };
 
         let actor_ref = ActorWrapper::new(
@@ -221,23 +651,37 @@ where
         let actor_handler = Executor::new(
             props,
             actor_address.clone(),
-            self.actor_config.clone(),
+            actor_config.clone(),
             mailbox.clone(),
             receiver,
+            control_receiver,
+            capacity_receiver,
             self.system.clone(),
             actor_ref.clone(),
+            self.wakeup_manager.clone(),
         );
 
         match actor_handler {
             Ok(a) => {
-                let result = self
-                    .system_state
-                    .add_mailbox(actor_address.clone(), mailbox);
+                let result = self.system_state.add_mailbox(
+                    actor_address.clone(),
+                    actor_config.shutdown_phase,
+                    mailbox,
+                );
 
                 if result.is_err() {
                     return Err(result.unwrap_err());
                 }
 
+                self.system_state.set_supervision_strategy(
+                    actor_address.clone(),
+                    actor_config.supervision_strategy,
+                );
+                if self.parent.is_some() {
+                    self.system_state
+                        .add_child(self.parent.clone().unwrap(), actor_address.clone());
+                }
+
                 self.wakeup_manager
                     .add_inactive_actor(a.get_address(), Arc::new(RwLock::new(a)));
 
@@ -1,8 +1,18 @@
+use crate::actor::mailbox_overflow_strategy::MailboxOverflowStrategy;
+use crate::actor::restart_policy::RestartPolicy;
+use crate::actor::supervision_strategy::SupervisionStrategy;
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct ActorConfig {
     pub pool_name: String,
     pub mailbox_size: usize,
+    pub mailbox_overflow_strategy: MailboxOverflowStrategy,
     pub message_throughput: usize,
+    pub sequential_async_handling: bool,
+    pub priority_mailbox: bool,
+    pub supervision_strategy: SupervisionStrategy,
+    pub restart_policy: RestartPolicy,
+    /// actors are stopped in ascending order of their phase during [ActorSystem.stop](../prelude/struct.ActorSystem.html#method.stop)
+    pub shutdown_phase: usize,
 }
@@ -0,0 +1,22 @@
+use crate::actor::actor_address::ActorAddress;
+use crate::actor::actor_state::ActorState;
+
+/// Type-erased snapshot of an actor, returned by [ActorSystem.list_actors](../prelude/struct.ActorSystem.html#method.list_actors)
+#[derive(Clone, Debug)]
+pub struct ActorInfo {
+    /// address of the actor, the thread-pool is available through `address.pool`
+    pub address: ActorAddress,
+    /// state of the actor at the time the snapshot was taken
+    pub state: ActorState,
+}
+
+impl ActorInfo {
+    pub fn new(address: ActorAddress, state: ActorState) -> Self {
+        Self { address, state }
+    }
+
+    /// Returns the name of the thread-pool the actor is running on
+    pub fn get_pool(&self) -> &str {
+        return &self.address.pool;
+    }
+}
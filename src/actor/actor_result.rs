@@ -12,5 +12,11 @@ pub enum ActorResult {
     /// stop immediately and ignore any remaining messages in the mailbox
     Kill,
     /// pauses message processing for the specified duration
+    ///
+    /// stopping or killing the actor, as well as stopping the system, ends the sleep immediately
     Sleep(Duration),
+    /// stop immediately and escalate the failure to the parent, which receives a [ActorError::ChildEscalationError](../prelude/enum.ActorError.html#variant.ChildEscalationError) in its [on_error](../prelude/trait.Actor.html#method.on_error)
+    ///
+    /// actors without a parent are stopped immediately
+    Escalate,
 }
@@ -9,4 +9,20 @@ pub enum ActorSendError {
     /// Triggered by [ActorWrapper.send](../prelude/struct.ActorWrapper.html#method.send) && [ActorWrapper.send_timeout](../prelude/struct.ActorWrapper.html#method.send_timout) when a message is sent to a stopped Actor
     #[error("Message could not be delivered")]
     AlreadyStoppedError,
+
+    /// Triggered by [ActorWrapper.ask](../prelude/struct.ActorWrapper.html#method.ask) if no response was received within the user defined timeout
+    #[error("Response was not received in time")]
+    ResponseTimeoutError,
+
+    /// Triggered by [ActorWrapper.ask](../prelude/struct.ActorWrapper.html#method.ask) && [ActorWrapper.ask_blocking](../prelude/struct.ActorWrapper.html#method.ask_blocking) if the request was dropped without a response, i.e. because the [RequestHandler](../prelude/trait.RequestHandler.html) returned an error or panicked
+    #[error("Request was not answered")]
+    NoResponseError,
+
+    /// Triggered by all send methods of [ActorWrapper](../prelude/struct.ActorWrapper.html) if the mailbox of the target is full and its [MailboxOverflowStrategy](../prelude/enum.MailboxOverflowStrategy.html) rejects new messages
+    #[error("Mailbox is full")]
+    MailboxFullError,
+
+    /// Triggered by [ActorWrapper.stop_and_wait](../prelude/struct.ActorWrapper.html#method.stop_and_wait) && [TerminationHandle.wait_timeout](../prelude/struct.TerminationHandle.html#method.wait_timeout) if the actor did not terminate within the user defined timeout
+    #[error("Actor did not terminate in time")]
+    TerminationTimeoutError,
 }
@@ -1,9 +1,14 @@
 use std::time::Duration;
 
 #[derive(PartialEq, Clone, Copy, Debug)]
+/// Current state of an actor, see [ActorWrapper.get_state](../prelude/struct.ActorWrapper.html#method.get_state)
 pub enum ActorState {
+    /// the actor is handling messages, or is scheduled to do so
     Running,
+    /// the mailbox of the actor is empty and it's waiting for new messages
     Inactive,
+    /// the actor has been told to stop and no longer accepts messages
     Stopped,
+    /// the actor does not handle any messages for the remaining duration, i.e. because of [ActorResult::Sleep](../prelude/enum.ActorResult.html#variant.Sleep)
     Sleeping(Duration),
 }
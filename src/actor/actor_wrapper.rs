@@ -1,16 +1,30 @@
 use crate::actor::actor_address::ActorAddress;
 use crate::actor::actor_send_error::ActorSendError;
+use crate::actor::actor_state::ActorState;
 use crate::actor::handler::Handler;
 use crate::actor::mailbox::Mailbox;
+use crate::actor::mailbox_overflow_strategy::MailboxOverflowStrategy;
+use crate::actor::recipient::Recipient;
+use crate::actor::request_handler::RequestHandler;
+use crate::actor::termination_handle::TerminationHandle;
+use crate::actor::termination_reason::TerminationReason;
+use crate::message::actor_kill_message::ActorKillMessage;
 use crate::message::actor_message::BaseActorMessage;
+use crate::message::actor_request::ActorRequest;
 use crate::message::actor_stop_message::ActorStopMessage;
+use crate::message::dead_letter::DeadLetter;
+use crate::message::dead_letter_reason::DeadLetterReason;
+use crate::message::request_message::RequestMessage;
 use crate::message::sleep_message::SleepMessage;
 use crate::prelude::Actor;
 use crate::system::internal_actor_manager::InternalActorManager;
+use crate::system::timer_handle::TimerHandle;
 use crate::system::wakeup_manager::WakeupManager;
+use std::any::type_name;
 use std::fmt::{Debug, Formatter};
 use std::panic::UnwindSafe;
-use std::time::Duration;
+use std::sync::atomic::Ordering;
+use std::time::{Duration, Instant};
 
 /// Wrapper used to interact with [Actor]
 pub struct ActorWrapper<A>
@@ -28,7 +42,7 @@ where
     A: Actor,
 {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        write!(f, "")
+        write!(f, "ActorWrapper({})", self.address)
     }
 }
 
@@ -55,29 +69,140 @@ where
 
     /// Sends a message to the actor that is then processed through the corresponding Handler<M> implementation
     /// Blocks until message has been sent, or fails if the target has been stopped
-    /// It is NOT recommended to use this to send messages to Actors with a limited mailbox. Use send_timeout() or send_after() for these cases
+    /// It is NOT recommended to use this to send messages to Actors with a limited mailbox, unless a non-blocking [MailboxOverflowStrategy](../prelude/enum.MailboxOverflowStrategy.html) is configured. Use send_timeout() or send_after() for these cases
     pub fn send<M>(&self, msg: M) -> Result<(), ActorSendError>
     where
         A: Handler<M>,
         M: BaseActorMessage + 'static,
     {
         if self.mailbox.is_stopped() {
+            self.send_dead_letter::<M>(DeadLetterReason::ActorStopped);
             return Err(ActorSendError::AlreadyStoppedError);
         }
 
         let result = self.mailbox.send(msg);
 
         if result.is_err() {
+            self.report_send_error::<M>(result.as_ref().unwrap_err());
             return result;
         }
 
-        if self.mailbox.is_sleeping() {
+        if self.mailbox.claim_wakeup() {
             self.wakeup_manager.wakeup(self.address.clone());
         }
 
         return Ok(());
     }
 
+    /// Same as send, but never blocks
+    /// Fails with [ActorSendError::MailboxFullError](../prelude/enum.ActorSendError.html#variant.MailboxFullError) if the mailbox is full, regardless of the configured [MailboxOverflowStrategy](../prelude/enum.MailboxOverflowStrategy.html)
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tyra::prelude::*;
+    /// use std::error::Error;
+    /// use std::sync::{Arc, Barrier};
+    /// use std::time::Duration;
+    ///
+    /// struct TestActor {}
+    /// impl Actor for TestActor {}
+    ///
+    /// struct TestActorFactory {}
+    /// impl ActorFactory<TestActor> for TestActorFactory {
+    ///     fn new_actor(&mut self, _context: ActorContext<TestActor>) -> Result<TestActor, Box<dyn Error>> {
+    ///         Ok(TestActor {})
+    ///     }
+    /// }
+    ///
+    /// struct Pause {
+    ///     started: Arc<Barrier>,
+    ///     resume: Arc<Barrier>,
+    /// }
+    /// impl ActorMessage for Pause {}
+    ///
+    /// struct FooBar {}
+    /// impl ActorMessage for FooBar {}
+    ///
+    /// impl Handler<Pause> for TestActor {
+    ///     fn handle(&mut self, msg: Pause, _context: &ActorContext<Self>) -> Result<ActorResult, Box<dyn Error>> {
+    ///         msg.started.wait();
+    ///         msg.resume.wait();
+    ///         Ok(ActorResult::Ok)
+    ///     }
+    /// }
+    ///
+    /// impl Handler<FooBar> for TestActor {
+    ///     fn handle(&mut self, _msg: FooBar, context: &ActorContext<Self>) -> Result<ActorResult, Box<dyn Error>> {
+    ///         context.system.stop(Duration::from_millis(1000));
+    ///         Ok(ActorResult::Ok)
+    ///     }
+    /// }
+    ///
+    /// #[ntest::timeout(10000)]
+    /// fn main() {
+    ///     let actor_config = TyraConfig::new().unwrap();
+    ///     let actor_system = ActorSystem::new(actor_config);
+    ///     let actor = actor_system.builder().set_mailbox_size(1).spawn("test", TestActorFactory {}).unwrap();
+    ///     assert_eq!(actor.get_mailbox_capacity(), Some(1));
+    ///
+    ///     let started = Arc::new(Barrier::new(2));
+    ///     let resume = Arc::new(Barrier::new(2));
+    ///     actor.try_send(Pause { started: started.clone(), resume: resume.clone() }).unwrap();
+    ///     // the actor is blocked in the handler and the mailbox is empty
+    ///     started.wait();
+    ///     assert_eq!(actor.get_state(), ActorState::Running);
+    ///
+    ///     actor.try_send(FooBar {}).unwrap();
+    ///     assert!(actor.is_mailbox_full());
+    ///     assert_eq!(actor.try_send(FooBar {}), Err(ActorSendError::MailboxFullError));
+    ///     resume.wait();
+    ///
+    ///     std::process::exit(actor_system.await_shutdown());
+    /// }
+    /// ```
+    pub fn try_send<M>(&self, msg: M) -> Result<(), ActorSendError>
+    where
+        A: Handler<M>,
+        M: BaseActorMessage + 'static,
+    {
+        if self.mailbox.is_stopped() {
+            self.send_dead_letter::<M>(DeadLetterReason::ActorStopped);
+            return Err(ActorSendError::AlreadyStoppedError);
+        }
+
+        let result = self.mailbox.try_send(msg);
+
+        if result.is_err() {
+            self.report_send_error::<M>(result.as_ref().unwrap_err());
+            return result;
+        }
+
+        if self.mailbox.claim_wakeup() {
+            self.wakeup_manager.wakeup(self.address.clone());
+        }
+
+        return Ok(());
+    }
+
+    /// Same as send, but never blocks the calling thread, used to deliver messages from the timer thread
+    ///
+    /// Mailboxes with [MailboxOverflowStrategy::Block] fail with [ActorSendError::MailboxFullError] instead of waiting for free space and the message is published as dead letter
+    pub(crate) fn send_non_blocking<M>(&self, msg: M) -> Result<(), ActorSendError>
+    where
+        A: Handler<M>,
+        M: BaseActorMessage + 'static,
+    {
+        if self.mailbox.overflow_strategy != MailboxOverflowStrategy::Block {
+            return self.send(msg);
+        }
+        let result = self.try_send(msg);
+        if result == Err(ActorSendError::MailboxFullError) {
+            self.send_dead_letter::<M>(DeadLetterReason::MailboxFull);
+        }
+        return result;
+    }
+
     /// Same as send, but with a user defined timeout
     pub fn send_timeout<M>(&self, msg: M, timeout: Duration) -> Result<(), ActorSendError>
     where
@@ -85,16 +210,83 @@ where
         M: BaseActorMessage + 'static,
     {
         if self.mailbox.is_stopped() {
+            self.send_dead_letter::<M>(DeadLetterReason::ActorStopped);
             return Err(ActorSendError::AlreadyStoppedError);
         }
 
         let result = self.mailbox.send_timeout(msg, timeout);
 
         if result.is_err() {
+            self.report_send_error::<M>(result.as_ref().unwrap_err());
+            return result;
+        }
+
+        if self.mailbox.claim_wakeup() {
+            self.wakeup_manager.wakeup(self.address.clone());
+        }
+
+        return Ok(());
+    }
+
+    /// Same as send, but returns a `Future` that can be awaited within any async runtime
+    /// Awaits free space in a limited mailbox without blocking the thread, or fails if the target has been stopped
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tyra::prelude::*;
+    /// use std::error::Error;
+    /// use std::time::Duration;
+    ///
+    /// struct TestActor {}
+    /// impl Actor for TestActor {}
+    ///
+    /// struct TestActorFactory {}
+    /// impl ActorFactory<TestActor> for TestActorFactory {
+    ///     fn new_actor(&mut self, _context: ActorContext<TestActor>) -> Result<TestActor, Box<dyn Error>> {
+    ///         Ok(TestActor {})
+    ///     }
+    /// }
+    ///
+    /// struct FooBar {}
+    /// impl ActorMessage for FooBar {}
+    ///
+    /// impl Handler<FooBar> for TestActor {
+    ///     fn handle(&mut self, _msg: FooBar, context: &ActorContext<Self>) -> Result<ActorResult, Box<dyn Error>> {
+    ///         context.system.stop(Duration::from_millis(1000));
+    ///         Ok(ActorResult::Ok)
+    ///     }
+    /// }
+    ///
+    /// #[ntest::timeout(10000)]
+    /// fn main() {
+    ///     let actor_config = TyraConfig::new().unwrap();
+    ///     let actor_system = ActorSystem::new(actor_config);
+    ///     let actor = actor_system.builder().set_mailbox_size(1).spawn("test", TestActorFactory {}).unwrap();
+    ///
+    ///     futures::executor::block_on(async {
+    ///         actor.send_async(FooBar {}).await.unwrap();
+    ///     });
+    ///     std::process::exit(actor_system.await_shutdown());
+    /// }
+    /// ```
+    pub async fn send_async<M>(&self, msg: M) -> Result<(), ActorSendError>
+    where
+        A: Handler<M>,
+        M: BaseActorMessage + 'static,
+    {
+        if self.mailbox.is_stopped() {
+            self.send_dead_letter::<M>(DeadLetterReason::ActorStopped);
+            return Err(ActorSendError::AlreadyStoppedError);
+        }
+
+        let result = self.mailbox.send_async(msg).await;
+        if result.is_err() {
+            self.report_send_error::<M>(result.as_ref().unwrap_err());
             return result;
         }
 
-        if self.mailbox.is_sleeping() {
+        if self.mailbox.claim_wakeup() {
             self.wakeup_manager.wakeup(self.address.clone());
         }
 
@@ -102,24 +294,310 @@ where
     }
 
     /// Sends a message to the actor after a specified delay
-    pub fn send_after<M>(&self, msg: M, delay: Duration) -> Result<(), ActorSendError>
+    ///
+    /// The returned [TimerHandle](../prelude/struct.TimerHandle.html) can be used to cancel the message before it has been sent
+    ///
+    /// The timer never waits for free space in a full mailbox with [MailboxOverflowStrategy::Block](../prelude/enum.MailboxOverflowStrategy.html#variant.Block), the message is published as dead letter instead
+    pub fn send_after<M>(&self, msg: M, delay: Duration) -> Result<TimerHandle, ActorSendError>
     where
         A: Handler<M> + 'static,
         M: BaseActorMessage + 'static,
     {
         if self.mailbox.is_stopped() {
+            self.send_dead_letter::<M>(DeadLetterReason::ActorStopped);
             return Err(ActorSendError::AlreadyStoppedError);
         }
 
-        self.internal_actor_manager
+        let timer = self
+            .internal_actor_manager
             .send_after(msg, self.clone(), delay);
 
-        return Ok(());
+        return Ok(timer);
+    }
+
+    /// Periodically sends a message created by `msg_factory` to the actor, until the returned [TimerHandle](../prelude/struct.TimerHandle.html) is cancelled or the actor is stopped
+    ///
+    /// Messages of a period in which the mailbox is full are dropped, see [send_after](#method.send_after)
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tyra::prelude::*;
+    /// use std::error::Error;
+    /// use std::time::Duration;
+    ///
+    /// struct TestActor {
+    ///     ticks: usize,
+    /// }
+    /// impl Actor for TestActor {}
+    ///
+    /// struct TestActorFactory {}
+    /// impl ActorFactory<TestActor> for TestActorFactory {
+    ///     fn new_actor(&mut self, _context: ActorContext<TestActor>) -> Result<TestActor, Box<dyn Error>> {
+    ///         Ok(TestActor { ticks: 0 })
+    ///     }
+    /// }
+    ///
+    /// struct Tick {}
+    /// impl ActorMessage for Tick {}
+    ///
+    /// impl Handler<Tick> for TestActor {
+    ///     fn handle(&mut self, _msg: Tick, context: &ActorContext<Self>) -> Result<ActorResult, Box<dyn Error>> {
+    ///         self.ticks += 1;
+    ///         if self.ticks == 3 {
+    ///             context.system.stop(Duration::from_millis(1000));
+    ///         }
+    ///         Ok(ActorResult::Ok)
+    ///     }
+    /// }
+    ///
+    /// #[ntest::timeout(10000)]
+    /// fn main() {
+    ///     let actor_config = TyraConfig::new().unwrap();
+    ///     let actor_system = ActorSystem::new(actor_config);
+    ///     let actor = actor_system.builder().spawn("test", TestActorFactory {}).unwrap();
+    ///
+    ///     let timer = actor.send_interval(|| Tick {}, Duration::from_millis(200)).unwrap();
+    ///     assert!(timer.is_active());
+    ///
+    ///     let cancelled = actor.send_after(Tick {}, Duration::from_millis(100)).unwrap();
+    ///     cancelled.cancel();
+    ///     assert!(!cancelled.is_active());
+    ///
+    ///     std::process::exit(actor_system.await_shutdown());
+    /// }
+    /// ```
+    pub fn send_interval<M, F>(
+        &self,
+        msg_factory: F,
+        period: Duration,
+    ) -> Result<TimerHandle, ActorSendError>
+    where
+        A: Handler<M> + 'static,
+        M: BaseActorMessage + 'static,
+        F: Fn() -> M + Send + Sync + 'static,
+    {
+        if self.mailbox.is_stopped() {
+            self.send_dead_letter::<M>(DeadLetterReason::ActorStopped);
+            return Err(ActorSendError::AlreadyStoppedError);
+        }
+
+        let timer = self
+            .internal_actor_manager
+            .send_interval(msg_factory, self.clone(), period);
+
+        return Ok(timer);
+    }
+
+    /// Sends a request to the actor that is then processed through the corresponding RequestHandler<M> implementation and returns the response
+    /// Blocks until the response has been received, or fails if the request could not be delivered and answered within the user defined timeout
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tyra::prelude::*;
+    /// use std::error::Error;
+    /// use std::time::Duration;
+    ///
+    /// struct TestActor {
+    ///     count: usize,
+    /// }
+    /// impl Actor for TestActor {}
+    ///
+    /// struct TestActorFactory {}
+    /// impl ActorFactory<TestActor> for TestActorFactory {
+    ///     fn new_actor(&mut self, _context: ActorContext<TestActor>) -> Result<TestActor, Box<dyn Error>> {
+    ///         Ok(TestActor { count: 0 })
+    ///     }
+    /// }
+    ///
+    /// struct Increment {}
+    /// impl ActorMessage for Increment {}
+    /// impl ActorRequest for Increment {
+    ///     type Response = usize;
+    /// }
+    ///
+    /// impl RequestHandler<Increment> for TestActor {
+    ///     fn handle(&mut self, _msg: Increment, _context: &ActorContext<Self>) -> Result<usize, Box<dyn Error>> {
+    ///         self.count += 1;
+    ///         Ok(self.count)
+    ///     }
+    /// }
+    ///
+    /// #[ntest::timeout(10000)]
+    /// fn main() {
+    ///     let actor_config = TyraConfig::new().unwrap();
+    ///     let actor_system = ActorSystem::new(actor_config);
+    ///     let actor = actor_system.builder().spawn("test", TestActorFactory {}).unwrap();
+    ///
+    ///     let first = actor.ask(Increment {}, Duration::from_secs(1)).unwrap();
+    ///     assert_eq!(1, first);
+    ///     let second = actor.ask_blocking(Increment {}).unwrap();
+    ///     assert_eq!(2, second);
+    ///
+    ///     actor_system.stop(Duration::from_millis(1000));
+    ///     std::process::exit(actor_system.await_shutdown());
+    /// }
+    /// ```
+    pub fn ask<M>(&self, msg: M, timeout: Duration) -> Result<M::Response, ActorSendError>
+    where
+        A: RequestHandler<M>,
+        M: ActorRequest + 'static,
+    {
+        let started = Instant::now();
+        let (reply_to, response) = flume::bounded(1);
+        self.send_timeout(RequestMessage::new(msg, reply_to), timeout)?;
+
+        let remaining = timeout.saturating_sub(started.elapsed());
+        return match response.recv_timeout(remaining) {
+            Ok(response) => Ok(response),
+            Err(flume::RecvTimeoutError::Timeout) => Err(ActorSendError::ResponseTimeoutError),
+            Err(flume::RecvTimeoutError::Disconnected) => Err(ActorSendError::NoResponseError),
+        };
+    }
+
+    /// Same as ask, but blocks until the response has been received without any timeout
+    pub fn ask_blocking<M>(&self, msg: M) -> Result<M::Response, ActorSendError>
+    where
+        A: RequestHandler<M>,
+        M: ActorRequest + 'static,
+    {
+        let (reply_to, response) = flume::bounded(1);
+        self.send(RequestMessage::new(msg, reply_to))?;
+
+        return response.recv().map_err(|_| ActorSendError::NoResponseError);
+    }
+
+    /// Same as ask, but returns a `Future` that resolves to the response and can be awaited within any async runtime
+    ///
+    /// There is no built-in timeout, use the timeout mechanism of the async runtime if required
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tyra::prelude::*;
+    /// use std::error::Error;
+    /// use std::time::Duration;
+    ///
+    /// struct TestActor {}
+    /// impl Actor for TestActor {}
+    ///
+    /// struct TestActorFactory {}
+    /// impl ActorFactory<TestActor> for TestActorFactory {
+    ///     fn new_actor(&mut self, _context: ActorContext<TestActor>) -> Result<TestActor, Box<dyn Error>> {
+    ///         Ok(TestActor {})
+    ///     }
+    /// }
+    ///
+    /// struct Ping {}
+    /// impl ActorMessage for Ping {}
+    /// impl ActorRequest for Ping {
+    ///     type Response = String;
+    /// }
+    ///
+    /// impl RequestHandler<Ping> for TestActor {
+    ///     fn handle(&mut self, _msg: Ping, _context: &ActorContext<Self>) -> Result<String, Box<dyn Error>> {
+    ///         Ok(String::from("pong"))
+    ///     }
+    /// }
+    ///
+    /// #[ntest::timeout(10000)]
+    /// fn main() {
+    ///     let actor_config = TyraConfig::new().unwrap();
+    ///     let actor_system = ActorSystem::new(actor_config);
+    ///     let actor = actor_system.builder().spawn("test", TestActorFactory {}).unwrap();
+    ///
+    ///     let response = futures::executor::block_on(actor.ask_async(Ping {})).unwrap();
+    ///     assert_eq!("pong", response);
+    ///
+    ///     actor_system.stop(Duration::from_millis(1000));
+    ///     std::process::exit(actor_system.await_shutdown());
+    /// }
+    /// ```
+    pub async fn ask_async<M>(&self, msg: M) -> Result<M::Response, ActorSendError>
+    where
+        A: RequestHandler<M>,
+        M: ActorRequest + 'static,
+    {
+        let (reply_to, response) = flume::bounded(1);
+        self.send_async(RequestMessage::new(msg, reply_to)).await?;
+
+        return response
+            .recv_async()
+            .await
+            .map_err(|_| ActorSendError::NoResponseError);
     }
 
     /// Tells the actor to stop accepting message and to shutdown after all existing messages have been processed
     pub fn stop(&self) -> Result<(), ActorSendError> {
-        return self.send(ActorStopMessage::new());
+        self.send(ActorStopMessage::new())?;
+        // a sleeping actor would otherwise only stop after its sleep
+        self.wakeup_manager.interrupt_sleep(&self.address);
+        return Ok(());
+    }
+
+    /// Same as [stop](#method.stop), but blocks until the actor has terminated and was removed from the system
+    ///
+    /// Fails with [ActorSendError::TerminationTimeoutError](../prelude/enum.ActorSendError.html#variant.TerminationTimeoutError) if the actor did not terminate within `timeout`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::error::Error;
+    /// use std::time::Duration;
+    /// use tyra::prelude::*;
+    ///
+    /// struct TestActor {}
+    /// impl Actor for TestActor {}
+    ///
+    /// struct TestActorFactory {}
+    /// impl ActorFactory<TestActor> for TestActorFactory {
+    ///     fn new_actor(&mut self, _context: ActorContext<TestActor>) -> Result<TestActor, Box<dyn Error>> {
+    ///         Ok(TestActor {})
+    ///     }
+    /// }
+    ///
+    /// #[ntest::timeout(10000)]
+    /// fn main() {
+    ///     let actor_config = TyraConfig::new().unwrap();
+    ///     let actor_system = ActorSystem::new(actor_config);
+    ///     let actor = actor_system.builder().spawn("test", TestActorFactory {}).unwrap();
+    ///
+    ///     let reason = actor.stop_and_wait(Duration::from_secs(5)).unwrap();
+    ///     assert_eq!(reason, TerminationReason::Stopped);
+    ///     assert_eq!(actor.get_state(), ActorState::Stopped);
+    ///     assert!(actor_system.get_actor::<TestActor>(actor.get_address()).is_err());
+    ///
+    ///     let killed = actor_system.builder().spawn("killed", TestActorFactory {}).unwrap();
+    ///     killed.kill().unwrap();
+    ///     assert_eq!(killed.get_termination_handle().wait(), TerminationReason::Killed);
+    ///
+    ///     // stopping an actor ends its sleep immediately
+    ///     let sleeping = actor_system.builder().spawn("sleeping", TestActorFactory {}).unwrap();
+    ///     sleeping.sleep(Duration::from_secs(30)).unwrap();
+    ///     assert_eq!(sleeping.stop_and_wait(Duration::from_secs(5)).unwrap(), TerminationReason::Stopped);
+    ///
+    ///     actor_system.stop(Duration::from_millis(1000));
+    ///     std::process::exit(actor_system.await_shutdown());
+    /// }
+    /// ```
+    pub fn stop_and_wait(&self, timeout: Duration) -> Result<TerminationReason, ActorSendError> {
+        let termination_handle = self.get_termination_handle();
+        // an actor that already stopped does not accept the message, but can still be awaited
+        let _ = self.stop();
+        return termination_handle.wait_timeout(timeout);
+    }
+
+    /// Tells the actor to stop immediately, all remaining messages in the mailbox are dropped
+    pub fn kill(&self) -> Result<(), ActorSendError> {
+        self.send(ActorKillMessage::new())?;
+        self.wakeup_manager.interrupt_sleep(&self.address);
+        return Ok(());
+    }
+
+    /// Returns a [TerminationHandle](../prelude/struct.TerminationHandle.html) that resolves once the actor has terminated and was removed from the system
+    pub fn get_termination_handle(&self) -> TerminationHandle {
+        return self.mailbox.termination_handle.clone();
     }
 
     /// Tells the actor to sleep for the specified duration
@@ -132,9 +610,189 @@ where
         &self.address
     }
 
+    /// Returns a type-erased [Recipient](../prelude/struct.Recipient.html) that can send messages of type `M` to the actor
+    pub fn get_recipient<M>(&self) -> Recipient<M>
+    where
+        A: Handler<M> + 'static,
+        M: BaseActorMessage + 'static,
+    {
+        return Recipient::from(self.clone());
+    }
+
+    /// Returns the amount of messages that are currently in the mailbox
+    ///
+    /// Includes messages that were already moved into the priority queue of a [priority mailbox](../prelude/struct.ActorBuilder.html#method.set_priority_mailbox)
     pub fn get_mailbox_size(&self) -> usize {
         return self.mailbox.len();
     }
+
+    /// Returns the maximum amount of messages the mailbox can hold, or `None` if the mailbox is unbounded
+    pub fn get_mailbox_capacity(&self) -> Option<usize> {
+        return self.mailbox.capacity();
+    }
+
+    /// Returns `true` if the bounded mailbox is full, unbounded mailboxes are never full
+    ///
+    /// Same as [get_mailbox_size](#method.get_mailbox_size), messages in the priority queue are taken into account
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tyra::prelude::*;
+    /// use std::error::Error;
+    /// use std::sync::{Arc, Barrier};
+    /// use std::time::Duration;
+    ///
+    /// struct TestActor {}
+    /// impl Actor for TestActor {}
+    ///
+    /// struct TestActorFactory {}
+    /// impl ActorFactory<TestActor> for TestActorFactory {
+    ///     fn new_actor(&mut self, _context: ActorContext<TestActor>) -> Result<TestActor, Box<dyn Error>> {
+    ///         Ok(TestActor {})
+    ///     }
+    /// }
+    ///
+    /// struct Pause {
+    ///     started: Arc<Barrier>,
+    ///     resume: Arc<Barrier>,
+    /// }
+    /// impl ActorMessage for Pause {
+    ///     fn get_priority(&self) -> usize {
+    ///         return 1;
+    ///     }
+    /// }
+    ///
+    /// struct FooBar {}
+    /// impl ActorMessage for FooBar {}
+    ///
+    /// impl Handler<Pause> for TestActor {
+    ///     fn handle(&mut self, msg: Pause, _context: &ActorContext<Self>) -> Result<ActorResult, Box<dyn Error>> {
+    ///         msg.started.wait();
+    ///         msg.resume.wait();
+    ///         Ok(ActorResult::Ok)
+    ///     }
+    /// }
+    ///
+    /// impl Handler<FooBar> for TestActor {
+    ///     fn handle(&mut self, _msg: FooBar, context: &ActorContext<Self>) -> Result<ActorResult, Box<dyn Error>> {
+    ///         context.system.stop(Duration::from_millis(1000));
+    ///         Ok(ActorResult::Ok)
+    ///     }
+    /// }
+    ///
+    /// #[ntest::timeout(10000)]
+    /// fn main() {
+    ///     let actor_config = TyraConfig::new().unwrap();
+    ///     let actor_system = ActorSystem::new(actor_config);
+    ///     let actor = actor_system
+    ///         .builder()
+    ///         .set_priority_mailbox(true)
+    ///         .set_mailbox_size(2)
+    ///         .spawn("test", TestActorFactory {})
+    ///         .unwrap();
+    ///
+    ///     let started = Arc::new(Barrier::new(2));
+    ///     let resume = Arc::new(Barrier::new(2));
+    ///     actor.try_send(Pause { started: started.clone(), resume: resume.clone() }).unwrap();
+    ///     started.wait();
+    ///     actor.try_send(FooBar {}).unwrap();
+    ///     actor.try_send(Pause { started: started.clone(), resume: resume.clone() }).unwrap();
+    ///     assert!(actor.is_mailbox_full());
+    ///     assert_eq!(actor.try_send(FooBar {}), Err(ActorSendError::MailboxFullError));
+    ///
+    ///     // the second pause is handled first, which leaves the first FooBar in the priority queue
+    ///     resume.wait();
+    ///     started.wait();
+    ///     assert_eq!(actor.get_mailbox_size(), 1);
+    ///     assert!(!actor.is_mailbox_full());
+    ///     actor.try_send(FooBar {}).unwrap();
+    ///     assert!(actor.is_mailbox_full());
+    ///     assert_eq!(actor.try_send(FooBar {}), Err(ActorSendError::MailboxFullError));
+    ///     resume.wait();
+    ///
+    ///     std::process::exit(actor_system.await_shutdown());
+    /// }
+    /// ```
+    pub fn is_mailbox_full(&self) -> bool {
+        return self.mailbox.is_full();
+    }
+
+    /// Changes the maximum amount of consecutive messages that the actor handles before the thread is switched to another actor
+    ///
+    /// The change is applied at the start of the next scheduling slice, values below `1` are treated as `1`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tyra::prelude::*;
+    /// use std::error::Error;
+    /// use std::time::Duration;
+    ///
+    /// struct TestActor {}
+    /// impl Actor for TestActor {}
+    ///
+    /// struct TestActorFactory {}
+    /// impl ActorFactory<TestActor> for TestActorFactory {
+    ///     fn new_actor(&mut self, _context: ActorContext<TestActor>) -> Result<TestActor, Box<dyn Error>> {
+    ///         Ok(TestActor {})
+    ///     }
+    /// }
+    ///
+    /// #[ntest::timeout(10000)]
+    /// fn main() {
+    ///     let actor_config = TyraConfig::new().unwrap();
+    ///     let actor_system = ActorSystem::new(actor_config);
+    ///     let actor = actor_system.builder().set_message_throughput(5).spawn("test", TestActorFactory {}).unwrap();
+    ///     assert_eq!(actor.get_message_throughput(), 5);
+    ///
+    ///     actor.set_message_throughput(100);
+    ///     assert_eq!(actor.get_message_throughput(), 100);
+    ///
+    ///     actor_system.stop(Duration::from_millis(1000));
+    ///     std::process::exit(actor_system.await_shutdown());
+    /// }
+    /// ```
+    pub fn set_message_throughput(&self, message_throughput: usize) {
+        self.mailbox
+            .message_throughput
+            .store(message_throughput.max(1), Ordering::Relaxed);
+    }
+
+    /// Returns the maximum amount of consecutive messages that the actor handles before the thread is switched to another actor
+    pub fn get_message_throughput(&self) -> usize {
+        return self.mailbox.message_throughput.load(Ordering::Relaxed);
+    }
+
+    /// Returns the current [ActorState](../prelude/enum.ActorState.html) of the actor
+    ///
+    /// The state can change at any time, it should only be used as a hint, i.e. for routing decisions
+    pub fn get_state(&self) -> ActorState {
+        return self.mailbox.get_state();
+    }
+
+    fn send_dead_letter<M>(&self, reason: DeadLetterReason) {
+        self.internal_actor_manager
+            .send_dead_letter(DeadLetter::new(
+                self.address.clone(),
+                type_name::<M>(),
+                reason,
+            ));
+    }
+
+    fn report_send_error<M>(&self, error: &ActorSendError) {
+        match error {
+            ActorSendError::AlreadyStoppedError => {
+                self.send_dead_letter::<M>(DeadLetterReason::ActorStopped)
+            }
+            ActorSendError::MailboxFullError
+                if self.mailbox.overflow_strategy == MailboxOverflowStrategy::DeadLetter =>
+            {
+                self.send_dead_letter::<M>(DeadLetterReason::MailboxFull)
+            }
+            _ => {}
+        }
+    }
 }
 
 impl<A> Clone for ActorWrapper<A>
@@ -0,0 +1,98 @@
+use crate::actor::actor::Actor;
+use crate::actor::context::ActorContext;
+use crate::message::actor_message::BaseActorMessage;
+use crate::prelude::ActorResult;
+use std::error::Error;
+use std::future::Future;
+use std::pin::Pin;
+
+/// Future returned by [AsyncHandler.handle](../prelude/trait.AsyncHandler.html#tymethod.handle)
+pub type ActorFuture = Pin<Box<dyn Future<Output = Result<ActorResult, Box<dyn Error>>> + Send>>;
+
+/// Defines which [ActorMessage](../prelude/trait.ActorMessage.html) is supported per [Actor] and handled asynchronously
+///
+/// Messages are sent wrapped in an [AsyncActorMessage](../prelude/struct.AsyncActorMessage.html)
+///
+/// The returned future is polled by the thread pool the actor is running on, without blocking a thread while it's pending.
+/// Because the future can't borrow the actor, everything that's required has to be moved into it, i.e. by cloning the [ActorContext]
+///
+/// The `ActorResult` of the future is applied once it completes. Pending futures are dropped if the actor is restarted or killed
+///
+/// Per default the actor continues to process messages while futures are pending, see [ActorBuilder.set_sequential_async_handling](../prelude/struct.ActorBuilder.html#method.set_sequential_async_handling) to change that behavior
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```rust
+/// use tyra::prelude::*;
+/// use std::error::Error;
+/// use std::time::Duration;
+///
+/// struct Ping {}
+/// impl ActorMessage for Ping {}
+/// impl ActorRequest for Ping {
+///     type Response = String;
+/// }
+///
+/// struct PongActor {}
+/// impl Actor for PongActor {}
+/// impl RequestHandler<Ping> for PongActor {
+///     fn handle(&mut self, _msg: Ping, _context: &ActorContext<Self>) -> Result<String, Box<dyn Error>> {
+///         Ok(String::from("pong"))
+///     }
+/// }
+///
+/// struct PongActorFactory {}
+/// impl ActorFactory<PongActor> for PongActorFactory {
+///     fn new_actor(&mut self, _context: ActorContext<PongActor>) -> Result<PongActor, Box<dyn Error>> {
+///         Ok(PongActor {})
+///     }
+/// }
+///
+/// struct Start {}
+/// impl ActorMessage for Start {}
+///
+/// struct PingActor {
+///     pong: ActorWrapper<PongActor>,
+/// }
+/// impl Actor for PingActor {}
+/// impl AsyncHandler<Start> for PingActor {
+///     fn handle(&mut self, _msg: Start, context: &ActorContext<Self>) -> ActorFuture {
+///         let pong = self.pong.clone();
+///         let context = context.clone();
+///         Box::pin(async move {
+///             let response = pong.ask_async(Ping {}).await?;
+///             assert_eq!("pong", response);
+///             context.system.stop(Duration::from_millis(1000));
+///             Ok(ActorResult::Ok)
+///         })
+///     }
+/// }
+///
+/// struct PingActorFactory {
+///     pong: ActorWrapper<PongActor>,
+/// }
+/// impl ActorFactory<PingActor> for PingActorFactory {
+///     fn new_actor(&mut self, _context: ActorContext<PingActor>) -> Result<PingActor, Box<dyn Error>> {
+///         Ok(PingActor { pong: self.pong.clone() })
+///     }
+/// }
+///
+/// #[ntest::timeout(10000)]
+/// fn main() {
+///     let actor_config = TyraConfig::new().unwrap();
+///     let actor_system = ActorSystem::new(actor_config);
+///     let pong = actor_system.builder().spawn("pong", PongActorFactory {}).unwrap();
+///     let ping = actor_system.builder().spawn("ping", PingActorFactory { pong }).unwrap();
+///     ping.send(AsyncActorMessage::new(Start {})).unwrap();
+///     std::process::exit(actor_system.await_shutdown());
+/// }
+/// ```
+pub trait AsyncHandler<M>
+where
+    Self: Actor + Sized,
+    M: BaseActorMessage,
+{
+    fn handle(&mut self, msg: M, context: &ActorContext<Self>) -> ActorFuture;
+}
@@ -1,7 +1,18 @@
+use crate::actor::actor_address::ActorAddress;
+use crate::actor::actor_builder::ActorBuilder;
+use crate::actor::actor_factory::ActorFactory;
+use crate::actor::actor_send_error::ActorSendError;
 use crate::actor::actor_wrapper::ActorWrapper;
-use crate::prelude::Actor;
+use crate::actor::async_handler::ActorFuture;
+use crate::actor::recipient::Recipient;
+use crate::message::actor_message::BaseActorMessage;
+use crate::prelude::{Actor, Handler, SerializedMessage};
+use crate::system::actor_error::ActorError;
 use crate::system::actor_system::ActorSystem;
+use crate::system::timer_handle::TimerHandle;
 use std::panic::UnwindSafe;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
 
 /// Enables access to [ActorSystem] and [Actor] within [Handler](./trait.Handler.html) implementations
 ///
@@ -13,10 +24,222 @@ where
 {
     pub actor_ref: ActorWrapper<A>,
     pub system: ActorSystem,
+    futures: Arc<Mutex<Vec<ActorFuture>>>,
+    timers: Arc<Mutex<Vec<TimerHandle>>>,
 }
 
 impl<A> UnwindSafe for ActorContext<A> where A: Actor {}
 
+impl<A> ActorContext<A>
+where
+    A: Actor,
+{
+    pub(crate) fn new(actor_ref: ActorWrapper<A>, system: ActorSystem) -> Self {
+        Self {
+            actor_ref,
+            system,
+            futures: Arc::new(Mutex::new(Vec::new())),
+            timers: Arc::new(Mutex::new(Vec::new())),
+        }
+    }
+
+    /// Returns an [ActorBuilder] that creates [Actor]s as children of the current actor
+    ///
+    /// Children are stopped when the parent stops and are supervised through the [SupervisionStrategy](../prelude/enum.SupervisionStrategy.html) of the parent
+    pub fn child_builder<B>(&self) -> ActorBuilder<B>
+    where
+        B: Handler<SerializedMessage> + 'static,
+    {
+        return self
+            .system
+            .builder()
+            .set_parent(self.actor_ref.get_address().clone());
+    }
+
+    /// Creates a child of the current actor with the default configuration, see [child_builder](#method.child_builder)
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tyra::prelude::*;
+    /// use std::error::Error;
+    /// use std::time::Duration;
+    ///
+    /// struct ChildActor {}
+    /// impl Actor for ChildActor {
+    ///     fn on_error(&mut self, _context: &ActorContext<Self>, _err: Box<dyn Error>) -> ActorResult {
+    ///         ActorResult::Restart
+    ///     }
+    /// }
+    ///
+    /// struct ChildActorFactory {}
+    /// impl ActorFactory<ChildActor> for ChildActorFactory {
+    ///     fn new_actor(&mut self, _context: ActorContext<ChildActor>) -> Result<ChildActor, Box<dyn Error>> {
+    ///         Ok(ChildActor {})
+    ///     }
+    /// }
+    ///
+    /// struct Fail {}
+    /// impl ActorMessage for Fail {}
+    ///
+    /// impl Handler<Fail> for ChildActor {
+    ///     fn handle(&mut self, _msg: Fail, _context: &ActorContext<Self>) -> Result<ActorResult, Box<dyn Error>> {
+    ///         Err(Box::new(std::io::Error::from_raw_os_error(1337)))
+    ///     }
+    /// }
+    ///
+    /// struct Start {}
+    /// impl ActorMessage for Start {}
+    ///
+    /// struct ParentActor {}
+    /// impl Handler<Start> for ParentActor {
+    ///     fn handle(&mut self, _msg: Start, context: &ActorContext<Self>) -> Result<ActorResult, Box<dyn Error>> {
+    ///         let child = context.spawn_child("child", ChildActorFactory {})?;
+    ///         child.send(Fail {})?;
+    ///         Ok(ActorResult::Ok)
+    ///     }
+    /// }
+    ///
+    /// impl Actor for ParentActor {
+    ///     fn on_error(&mut self, context: &ActorContext<Self>, err: Box<dyn Error>) -> ActorResult {
+    ///         let err = err.downcast_ref::<ActorError>().unwrap();
+    ///         assert!(matches!(err, ActorError::ChildEscalationError { child } if child.actor == "child"));
+    ///         context.system.stop(Duration::from_millis(1000));
+    ///         ActorResult::Ok
+    ///     }
+    /// }
+    ///
+    /// struct ParentActorFactory {}
+    /// impl ActorFactory<ParentActor> for ParentActorFactory {
+    ///     fn new_actor(&mut self, _context: ActorContext<ParentActor>) -> Result<ParentActor, Box<dyn Error>> {
+    ///         Ok(ParentActor {})
+    ///     }
+    /// }
+    ///
+    /// #[ntest::timeout(10000)]
+    /// fn main() {
+    ///     let actor_config = TyraConfig::new().unwrap();
+    ///     let actor_system = ActorSystem::new(actor_config);
+    ///     let parent = actor_system
+    ///         .builder()
+    ///         .set_supervision_strategy(SupervisionStrategy::Escalate)
+    ///         .spawn("parent", ParentActorFactory {})
+    ///         .unwrap();
+    ///     parent.send(Start {}).unwrap();
+    ///     std::process::exit(actor_system.await_shutdown());
+    /// }
+    /// ```
+    pub fn spawn_child<B, P>(
+        &self,
+        name: impl Into<String>,
+        props: P,
+    ) -> Result<ActorWrapper<B>, ActorError>
+    where
+        B: Handler<SerializedMessage> + 'static,
+        P: ActorFactory<B> + 'static,
+    {
+        return self.child_builder().spawn(name, props);
+    }
+
+    /// Registers the current actor to receive an [ActorTerminated](../prelude/struct.ActorTerminated.html) message when the watched actor terminates
+    ///
+    /// If the watched actor already terminated, the message is sent immediately
+    ///
+    /// See [Actor.on_actor_terminated](../prelude/trait.Actor.html#method.on_actor_terminated) for a detailed example
+    pub fn watch<B>(&self, actor: &ActorWrapper<B>)
+    where
+        B: Actor,
+    {
+        self.watch_address(actor.get_address());
+    }
+
+    /// Stops watching an actor that was previously watched through [watch](#method.watch)
+    pub fn unwatch<B>(&self, actor: &ActorWrapper<B>)
+    where
+        B: Actor,
+    {
+        self.unwatch_address(actor.get_address());
+    }
+
+    /// Same as [watch](#method.watch), but for the actor behind a [Recipient](../prelude/struct.Recipient.html)
+    pub fn watch_recipient<M>(&self, recipient: &Recipient<M>)
+    where
+        M: BaseActorMessage + 'static,
+    {
+        self.watch_address(recipient.get_address());
+    }
+
+    /// Stops watching an actor that was previously watched through [watch_recipient](#method.watch_recipient)
+    pub fn unwatch_recipient<M>(&self, recipient: &Recipient<M>)
+    where
+        M: BaseActorMessage + 'static,
+    {
+        self.unwatch_address(recipient.get_address());
+    }
+
+    pub(crate) fn watch_address(&self, address: &ActorAddress) {
+        self.system
+            .get_state()
+            .watch(self.actor_ref.get_address().clone(), address.clone());
+    }
+
+    pub(crate) fn unwatch_address(&self, address: &ActorAddress) {
+        self.system
+            .get_state()
+            .unwatch(self.actor_ref.get_address(), address);
+    }
+
+    /// Same as [ActorWrapper.send_after](../prelude/struct.ActorWrapper.html#method.send_after) to the current actor, but the timer is automatically cancelled when the actor stops or restarts
+    pub fn send_after<M>(&self, msg: M, delay: Duration) -> Result<TimerHandle, ActorSendError>
+    where
+        A: Handler<M> + 'static,
+        M: BaseActorMessage + 'static,
+    {
+        let timer = self.actor_ref.send_after(msg, delay)?;
+        self.add_timer(timer.clone());
+        return Ok(timer);
+    }
+
+    /// Same as [ActorWrapper.send_interval](../prelude/struct.ActorWrapper.html#method.send_interval) to the current actor, but the timer is automatically cancelled when the actor stops or restarts
+    pub fn send_interval<M, F>(
+        &self,
+        msg_factory: F,
+        period: Duration,
+    ) -> Result<TimerHandle, ActorSendError>
+    where
+        A: Handler<M> + 'static,
+        M: BaseActorMessage + 'static,
+        F: Fn() -> M + Send + Sync + 'static,
+    {
+        let timer = self.actor_ref.send_interval(msg_factory, period)?;
+        self.add_timer(timer.clone());
+        return Ok(timer);
+    }
+
+    fn add_timer(&self, timer: TimerHandle) {
+        let mut timers = self.timers.lock().unwrap();
+        timers.retain(|t| t.is_active());
+        timers.push(timer);
+    }
+
+    pub(crate) fn cancel_timers(&self) {
+        let mut timers = self.timers.lock().unwrap();
+        for timer in timers.drain(..) {
+            timer.cancel();
+        }
+    }
+
+    /// hands a future that was created by an [AsyncHandler](../prelude/trait.AsyncHandler.html) over to the executor of the actor
+    pub(crate) fn add_future(&self, future: ActorFuture) {
+        self.futures.lock().unwrap().push(future);
+    }
+
+    pub(crate) fn take_futures(&self) -> Vec<ActorFuture> {
+        let mut futures = self.futures.lock().unwrap();
+        return std::mem::take(&mut *futures);
+    }
+}
+
 impl<A> Clone for ActorContext<A>
 where
     A: Actor,
@@ -25,6 +248,8 @@ where
         Self {
             system: self.system.clone(),
             actor_ref: self.actor_ref.clone(),
+            futures: self.futures.clone(),
+            timers: self.timers.clone(),
         }
     }
 }
@@ -1,34 +1,51 @@
 use crate::actor::actor_address::ActorAddress;
 use crate::actor::actor_config::ActorConfig;
 use crate::actor::actor_factory::ActorFactory;
+use crate::actor::actor_send_error::ActorSendError;
 use crate::actor::actor_state::ActorState;
 use crate::actor::actor_wrapper::ActorWrapper;
+use crate::actor::async_handler::ActorFuture;
 use crate::actor::context::ActorContext;
+use crate::actor::future_waker::FutureWaker;
 use crate::actor::handler::Handler;
 use crate::actor::mailbox::Mailbox;
+use crate::actor::restart_policy::RestartLimitAction;
+use crate::actor::supervision_strategy::SupervisionStrategy;
+use crate::actor::termination_reason::TerminationReason;
 use crate::message::actor_message::BaseActorMessage;
-use crate::message::envelope::{MessageEnvelope, MessageEnvelopeTrait};
+use crate::message::dead_letter::DeadLetter;
+use crate::message::dead_letter_reason::DeadLetterReason;
+use crate::message::envelope::{MessageEnvelope, MessageEnvelopeTrait, PrioritizedMessageEnvelope};
 use crate::message::system_stop_message::SystemStopMessage;
 use crate::prelude::{Actor, ActorPanicSource, ActorResult};
 use crate::system::actor_error::ActorError;
 use crate::system::actor_system::ActorSystem;
+use crate::system::wakeup_manager::WakeupManager;
 use log::debug;
+use std::collections::{BinaryHeap, VecDeque};
 use std::error::Error;
 use std::panic::{catch_unwind, AssertUnwindSafe};
-use std::sync::atomic::Ordering;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::task::{Context, Poll, Waker};
 use std::time::{Duration, Instant};
 
 pub trait ExecutorTrait: Send + Sync {
     fn handle(&mut self, is_system_stopping: bool) -> ActorState;
     fn get_config(&self) -> &ActorConfig;
+    /// applies config changes of the [ActorWrapper](../prelude/struct.ActorWrapper.html), called before each scheduling slice
+    fn apply_config_updates(&mut self);
     fn get_address(&self) -> ActorAddress;
-    fn is_sleeping(&self) -> bool;
     fn is_stopped(&self) -> bool;
-    fn wakeup(&mut self);
+    fn get_termination_reason(&self) -> TerminationReason;
+    fn set_sleeping_until(&self, sleeping_until: Option<Instant>);
     fn on_actor_panic(&mut self, source: ActorPanicSource) -> ActorState;
     fn restart_actor(&mut self) -> ActorState;
     fn stop_actor(&mut self, immediately: bool) -> ActorState;
     fn handle_actor_result(&mut self, result: Result<ActorResult, Box<dyn Error>>) -> ActorState;
+    fn handle_actor_failure(&mut self, result: ActorResult) -> ActorState;
+    fn escalate(&mut self) -> ActorState;
+    fn poll_futures(&mut self) -> ActorState;
 }
 
 pub struct Executor<A, P>
@@ -41,11 +58,21 @@ where
     actor_config: ActorConfig,
     mailbox: Mailbox<A>,
     queue: flume::Receiver<MessageEnvelope<A>>,
+    control_queue: flume::Receiver<MessageEnvelope<A>>,
+    /// releases the slot of a message of a bounded priority mailbox once it is handled
+    capacity_out: Option<flume::Receiver<()>>,
+    priority_queue: BinaryHeap<PrioritizedMessageEnvelope<A>>,
+    message_sequence: usize,
     actor_address: ActorAddress,
     is_startup: bool,
     system_triggered_stop: bool,
-    last_wakeup: Instant,
     context: ActorContext<A>,
+    termination_reason: TerminationReason,
+    restarts: VecDeque<Instant>,
+    is_restart_pending: bool,
+    pending_futures: Vec<ActorFuture>,
+    futures_woken: Arc<AtomicBool>,
+    future_waker: Arc<FutureWaker>,
 }
 
 unsafe impl<A, P> Send for Executor<A, P>
@@ -73,48 +100,95 @@ where
                 self.system_triggered_stop = true;
             }
         }
+        if self.is_restart_pending {
+            self.is_restart_pending = false;
+            let actor_state = self.recreate_actor();
+            if actor_state != ActorState::Running {
+                return actor_state;
+            }
+        }
         if self.is_startup {
             self.is_startup = false;
             let result = catch_unwind(AssertUnwindSafe(|| {
                 return self.actor.pre_start(&self.context);
             }));
-            return if result.is_err() {
+            let actor_state = if result.is_err() {
                 self.on_actor_panic(ActorPanicSource::PreStart)
             } else {
                 self.handle_actor_result(result.unwrap())
             };
+            if actor_state == ActorState::Stopped {
+                self.termination_reason = TerminationReason::InitFailed;
+            }
+            return actor_state;
         }
-        let _len = self.queue.len();
-        let m = self.queue.try_recv();
-
-        if m.is_err() {
-            if self.is_stopped() {
-                let _ = catch_unwind(AssertUnwindSafe(|| self.actor.post_stop(&self.context)));
-                return ActorState::Stopped;
+        if self.futures_woken.swap(false, Ordering::SeqCst) {
+            let actor_state = self.poll_futures();
+            if actor_state != ActorState::Running {
+                return actor_state;
             }
-            self.mailbox.is_sleeping.store(true, Ordering::Relaxed);
-            let duration = self.last_wakeup.elapsed();
-            if duration >= Duration::from_millis(5000) {
-                return ActorState::Inactive;
+        }
+
+        let is_awaiting_future =
+            self.actor_config.sequential_async_handling && !self.pending_futures.is_empty();
+        let m = self.next_message(is_awaiting_future);
+        if m.is_some() {
+            let mut msg = m.unwrap();
+            let result = catch_unwind(AssertUnwindSafe(|| {
+                let actor_result = msg.handle(&mut self.actor, &self.context);
+                self.collect_futures();
+                return self.handle_actor_result(actor_result);
+            }));
+            if result.is_err() {
+                return self.on_actor_panic(ActorPanicSource::Message);
             }
-            self.mailbox.is_sleeping.store(false, Ordering::Relaxed);
-            return ActorState::Running;
+            return result.unwrap();
         }
 
-        let mut msg = m.unwrap();
-        let result = catch_unwind(AssertUnwindSafe(|| {
-            let actor_result = msg.handle(&mut self.actor, &self.context);
-            return self.handle_actor_result(actor_result);
-        }));
-        if result.is_err() {
-            return self.on_actor_panic(ActorPanicSource::Message);
+        let is_queue_empty = self.queue.is_empty() && self.priority_queue.is_empty();
+        if self.is_stopped()
+            && self.pending_futures.is_empty()
+            && is_queue_empty
+            && self.control_queue.is_empty()
+        {
+            self.context.cancel_timers();
+            let _ = catch_unwind(AssertUnwindSafe(|| self.actor.post_stop(&self.context)));
+            return ActorState::Stopped;
         }
-        return result.unwrap();
+        self.mailbox.is_sleeping.store(true, Ordering::SeqCst);
+        // anything that arrived before the actor was marked as sleeping did not trigger a wakeup
+        let has_work = self.futures_woken.load(Ordering::SeqCst)
+            || !self.control_queue.is_empty()
+            || (!is_awaiting_future && !is_queue_empty)
+            || (self
+                .context
+                .system
+                .get_state()
+                .is_phase_stopping(self.actor_config.shutdown_phase)
+                && !self.system_triggered_stop);
+        // if the mailbox has already been woken up by someone else, the actor will be rescheduled by them
+        if has_work && self.mailbox.claim_wakeup() {
+            return ActorState::Running;
+        }
+        return ActorState::Inactive;
     }
 
     fn stop_actor(&mut self, immediately: bool) -> ActorState {
         self.mailbox.is_stopped.store(true, Ordering::Relaxed);
         if immediately {
+            self.termination_reason = TerminationReason::Killed;
+            self.pending_futures.clear();
+            self.mailbox.prioritized_len.store(0, Ordering::Relaxed);
+            let prioritized = self.priority_queue.drain().map(|msg| msg.envelope);
+            let remaining = self.control_queue.try_iter().chain(prioritized);
+            for msg in remaining.chain(self.queue.try_iter()) {
+                self.context.system.send_dead_letter(DeadLetter::new(
+                    self.actor_address.clone(),
+                    msg.get_type_name(),
+                    DeadLetterReason::ActorKilled,
+                ));
+            }
+            self.context.cancel_timers();
             let _ = catch_unwind(AssertUnwindSafe(|| self.actor.post_stop(&self.context)));
             return ActorState::Stopped;
         }
@@ -122,27 +196,38 @@ where
     }
 
     fn restart_actor(&mut self) -> ActorState {
-        let result = catch_unwind(AssertUnwindSafe(|| {
-            self.actor.pre_restart(&self.context);
-            let actor = self.actor_props.new_actor(self.context.clone());
-            if actor.is_err() {
-                let err = actor.as_ref().err().unwrap();
-                debug!("{:?}", err);
-            }
-            return actor.unwrap();
-        }));
-        if result.is_err() {
-            return self.on_actor_panic(ActorPanicSource::Restart);
-        } else {
-            self.actor = result.unwrap();
-            self.is_startup = true;
+        let restart_policy = &self.actor_config.restart_policy;
+        let restart_window = restart_policy.restart_window;
+        while self.restarts.front().is_some()
+            && self.restarts.front().unwrap().elapsed() >= restart_window
+        {
+            self.restarts.pop_front();
         }
-        return ActorState::Running;
+
+        if restart_policy.max_restarts != 0 && self.restarts.len() >= restart_policy.max_restarts {
+            self.restarts.clear();
+            return match restart_policy.on_limit_reached {
+                RestartLimitAction::Stop => self.stop_actor(true),
+                RestartLimitAction::Escalate => self.escalate(),
+            };
+        }
+
+        let backoff = restart_policy.get_backoff(self.restarts.len());
+        self.restarts.push_back(Instant::now());
+        if !backoff.is_zero() {
+            // the actor is recreated as soon as it is woken up again
+            self.is_restart_pending = true;
+            return ActorState::Sleeping(backoff);
+        }
+        return self.recreate_actor();
     }
 
     fn on_actor_panic(&mut self, source: ActorPanicSource) -> ActorState {
         let result = catch_unwind(AssertUnwindSafe(|| {
             let actor_result = self.actor.on_panic(&self.context, source);
+            if actor_result.is_ok() {
+                return self.handle_actor_failure(actor_result.unwrap());
+            }
             return self.handle_actor_result(actor_result);
         }));
         if result.is_err() {
@@ -150,6 +235,9 @@ where
                 let actor_result = self
                     .actor
                     .on_panic(&self.context, ActorPanicSource::OnPanic);
+                if actor_result.is_ok() {
+                    return self.handle_actor_failure(actor_result.unwrap());
+                }
                 return self.handle_actor_result(actor_result);
             }));
             if result.is_err() {
@@ -164,25 +252,28 @@ where
         &self.actor_config
     }
 
-    fn get_address(&self) -> ActorAddress {
-        self.actor_address.clone()
+    fn apply_config_updates(&mut self) {
+        self.actor_config.message_throughput =
+            self.mailbox.message_throughput.load(Ordering::Relaxed);
     }
 
-    fn is_sleeping(&self) -> bool {
-        self.mailbox.is_sleeping.load(Ordering::Relaxed)
+    fn get_address(&self) -> ActorAddress {
+        self.actor_address.clone()
     }
 
     fn is_stopped(&self) -> bool {
         self.mailbox.is_stopped.load(Ordering::Relaxed)
     }
 
-    fn wakeup(&mut self) {
-        self.mailbox.is_sleeping.store(false, Ordering::Relaxed);
-        self.last_wakeup = Instant::now();
+    fn get_termination_reason(&self) -> TerminationReason {
+        return self.termination_reason;
+    }
+
+    fn set_sleeping_until(&self, sleeping_until: Option<Instant>) {
+        *self.mailbox.sleeping_until.lock().unwrap() = sleeping_until;
     }
 
     fn handle_actor_result(&mut self, result: Result<ActorResult, Box<dyn Error>>) -> ActorState {
-        let res: ActorResult;
         if result.is_err() {
             let catch_result = catch_unwind(AssertUnwindSafe(|| {
                 let actor_result = self.actor.on_error(&self.context, result.unwrap_err());
@@ -191,18 +282,78 @@ where
             if catch_result.is_err() {
                 return self.stop_actor(true);
             }
-            res = catch_result.unwrap();
-        } else {
-            res = result.unwrap();
+            return self.handle_actor_failure(catch_result.unwrap());
         }
-        return match res {
+        return match result.unwrap() {
             ActorResult::Ok => ActorState::Running,
             ActorResult::Restart => self.restart_actor(),
             ActorResult::Stop => self.stop_actor(false),
             ActorResult::Kill => self.stop_actor(true),
             ActorResult::Sleep(duration) => ActorState::Sleeping(duration),
+            ActorResult::Escalate => self.escalate(),
+        };
+    }
+
+    fn handle_actor_failure(&mut self, result: ActorResult) -> ActorState {
+        if result != ActorResult::Restart && result != ActorResult::Kill {
+            return self.handle_actor_result(Ok(result));
+        }
+        let state = self.context.system.get_state();
+        let strategy = state.get_parent_supervision_strategy(&self.actor_address);
+        return match strategy {
+            Some(SupervisionStrategy::AllForOne) => {
+                state.supervise_siblings(&self.actor_address, result == ActorResult::Kill);
+                self.handle_actor_result(Ok(result))
+            }
+            Some(SupervisionStrategy::Escalate) => self.escalate(),
+            _ => self.handle_actor_result(Ok(result)),
         };
     }
+
+    fn escalate(&mut self) -> ActorState {
+        self.context
+            .system
+            .get_state()
+            .escalate(&self.actor_address);
+        return self.stop_actor(true);
+    }
+
+    fn poll_futures(&mut self) -> ActorState {
+        let waker = Waker::from(self.future_waker.clone());
+        let mut task_context = Context::from_waker(&waker);
+        let mut index = 0;
+        while index < self.pending_futures.len() {
+            let future = &mut self.pending_futures[index];
+            let poll = catch_unwind(AssertUnwindSafe(|| future.as_mut().poll(&mut task_context)));
+            let actor_state = match poll {
+                Ok(Poll::Pending) => {
+                    index += 1;
+                    continue;
+                }
+                Ok(Poll::Ready(actor_result)) => {
+                    drop(self.pending_futures.remove(index));
+                    let result = catch_unwind(AssertUnwindSafe(|| {
+                        return self.handle_actor_result(actor_result);
+                    }));
+                    if result.is_err() {
+                        self.on_actor_panic(ActorPanicSource::Message)
+                    } else {
+                        result.unwrap()
+                    }
+                }
+                Err(_) => {
+                    drop(self.pending_futures.remove(index));
+                    self.on_actor_panic(ActorPanicSource::Message)
+                }
+            };
+            if actor_state != ActorState::Running {
+                // remaining futures have not been polled yet
+                self.futures_woken.store(true, Ordering::SeqCst);
+                return actor_state;
+            }
+        }
+        return ActorState::Running;
+    }
 }
 
 impl<A, P> Executor<A, P>
@@ -216,13 +367,20 @@ where
         actor_config: ActorConfig,
         mailbox: Mailbox<A>,
         receiver: flume::Receiver<MessageEnvelope<A>>,
+        control_receiver: flume::Receiver<MessageEnvelope<A>>,
+        capacity_receiver: Option<flume::Receiver<()>>,
         system: ActorSystem,
         actor_ref: ActorWrapper<A>,
+        wakeup_manager: WakeupManager,
     ) -> Result<Self, ActorError> {
-        let context = ActorContext {
-            actor_ref,
-            system: system.clone(),
-        };
+        let context = ActorContext::new(actor_ref, system.clone());
+        let futures_woken = Arc::new(AtomicBool::new(false));
+        let future_waker = Arc::new(FutureWaker {
+            is_woken: futures_woken.clone(),
+            is_sleeping: mailbox.is_sleeping.clone(),
+            actor_address: actor_address.clone(),
+            wakeup_manager,
+        });
 
         let actor = catch_unwind(AssertUnwindSafe(|| {
             let to_return = actor_props.new_actor(context.clone());
@@ -241,21 +399,92 @@ where
             actor_config,
             mailbox,
             queue: receiver,
+            control_queue: control_receiver,
+            capacity_out: capacity_receiver,
+            priority_queue: BinaryHeap::new(),
+            message_sequence: 0,
             actor_address,
             is_startup: true,
             system_triggered_stop: false,
-            last_wakeup: Instant::now(),
             context,
+            termination_reason: TerminationReason::Stopped,
+            restarts: VecDeque::new(),
+            is_restart_pending: false,
+            pending_futures: Vec::new(),
+            futures_woken,
+            future_waker,
         });
     }
-    pub fn send<M>(&self, msg: M) -> Result<(), flume::SendTimeoutError<MessageEnvelope<A>>>
+
+    fn recreate_actor(&mut self) -> ActorState {
+        let result = catch_unwind(AssertUnwindSafe(|| {
+            self.actor.pre_restart(&self.context);
+            let actor = self.actor_props.new_actor(self.context.clone());
+            if actor.is_err() {
+                let err = actor.as_ref().err().unwrap();
+                debug!("{:?}", err);
+            }
+            return actor.unwrap();
+        }));
+        if result.is_err() {
+            let actor_state = self.on_actor_panic(ActorPanicSource::Restart);
+            if actor_state == ActorState::Stopped {
+                self.termination_reason = TerminationReason::InitFailed;
+            }
+            return actor_state;
+        } else {
+            self.actor = result.unwrap();
+            self.is_startup = true;
+            self.pending_futures.clear();
+            self.context.take_futures();
+            self.context.cancel_timers();
+        }
+        return ActorState::Running;
+    }
+
+    /// control messages are always handled first, even while awaiting a future
+    fn next_message(&mut self, is_awaiting_future: bool) -> Option<MessageEnvelope<A>> {
+        let control_message = self.control_queue.try_recv();
+        if control_message.is_ok() {
+            return Some(control_message.unwrap());
+        }
+        if is_awaiting_future {
+            return None;
+        }
+        if !self.actor_config.priority_mailbox {
+            return self.queue.try_recv().ok();
+        }
+
+        // bounded priority mailboxes keep applying backpressure through `capacity_out` until the message is handled
+        while let Ok(msg) = self.queue.try_recv() {
+            self.message_sequence += 1;
+            self.priority_queue
+                .push(PrioritizedMessageEnvelope::new(msg, self.message_sequence));
+        }
+        let msg = self.priority_queue.pop().map(|msg| msg.envelope);
+        if let (Some(_), Some(capacity)) = (&msg, &self.capacity_out) {
+            let _ = capacity.try_recv();
+        }
+        self.mailbox
+            .prioritized_len
+            .store(self.priority_queue.len(), Ordering::Relaxed);
+        return msg;
+    }
+
+    fn collect_futures(&mut self) {
+        let futures = self.context.take_futures();
+        if !futures.is_empty() {
+            self.pending_futures.extend(futures);
+            self.futures_woken.store(true, Ordering::SeqCst);
+        }
+    }
+
+    /// control messages are never blocked by a full mailbox
+    pub fn send<M>(&self, msg: M) -> Result<(), ActorSendError>
     where
         A: Handler<M>,
         M: BaseActorMessage + 'static,
     {
-        return self
-            .mailbox
-            .msg_in
-            .send_timeout(MessageEnvelope::new(msg), Duration::from_millis(10));
+        return self.mailbox.send_timeout(msg, Duration::from_millis(10));
     }
 }
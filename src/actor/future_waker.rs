@@ -0,0 +1,26 @@
+use crate::actor::actor_address::ActorAddress;
+use crate::system::wakeup_manager::WakeupManager;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::task::Wake;
+
+/// Wakes up the executor of an actor whenever one of its pending [ActorFuture](../prelude/type.ActorFuture.html)s can make progress
+pub struct FutureWaker {
+    pub is_woken: Arc<AtomicBool>,
+    pub is_sleeping: Arc<AtomicBool>,
+    pub actor_address: ActorAddress,
+    pub wakeup_manager: WakeupManager,
+}
+
+impl Wake for FutureWaker {
+    fn wake(self: Arc<Self>) {
+        self.wake_by_ref();
+    }
+
+    fn wake_by_ref(self: &Arc<Self>) {
+        self.is_woken.store(true, Ordering::SeqCst);
+        if self.is_sleeping.swap(false, Ordering::SeqCst) {
+            self.wakeup_manager.wakeup(self.actor_address.clone());
+        }
+    }
+}
@@ -1,10 +1,19 @@
 use crate::actor::actor::Actor;
+use crate::actor::async_handler::AsyncHandler;
 use crate::actor::context::ActorContext;
+use crate::actor::request_handler::RequestHandler;
+use crate::message::actor_kill_message::ActorKillMessage;
 use crate::message::actor_message::BaseActorMessage;
+use crate::message::actor_request::ActorRequest;
+use crate::message::actor_restart_message::ActorRestartMessage;
 use crate::message::actor_stop_message::ActorStopMessage;
+use crate::message::actor_terminated::ActorTerminated;
+use crate::message::async_actor_message::AsyncActorMessage;
+use crate::message::child_escalation_message::ChildEscalationMessage;
+use crate::message::request_message::RequestMessage;
 use crate::message::sleep_message::SleepMessage;
 use crate::message::system_stop_message::SystemStopMessage;
-use crate::prelude::{ActorResult, BulkActorMessage, SerializedMessage};
+use crate::prelude::{ActorError, ActorResult, BulkActorMessage, SerializedMessage};
 use std::error::Error;
 
 /// Defines which [ActorMessage] is supported per [Actor]
@@ -54,6 +63,60 @@ where
     }
 }
 
+impl<A> Handler<ActorKillMessage> for A
+where
+    A: Actor + Sized,
+{
+    fn handle(
+        &mut self,
+        _msg: ActorKillMessage,
+        _context: &ActorContext<A>,
+    ) -> Result<ActorResult, Box<dyn Error>> {
+        return Ok(ActorResult::Kill);
+    }
+}
+
+impl<A> Handler<ActorRestartMessage> for A
+where
+    A: Actor + Sized,
+{
+    fn handle(
+        &mut self,
+        _msg: ActorRestartMessage,
+        _context: &ActorContext<A>,
+    ) -> Result<ActorResult, Box<dyn Error>> {
+        return Ok(ActorResult::Restart);
+    }
+}
+
+impl<A> Handler<ChildEscalationMessage> for A
+where
+    A: Actor + Sized,
+{
+    fn handle(
+        &mut self,
+        msg: ChildEscalationMessage,
+        _context: &ActorContext<A>,
+    ) -> Result<ActorResult, Box<dyn Error>> {
+        return Err(Box::new(ActorError::ChildEscalationError {
+            child: msg.child,
+        }));
+    }
+}
+
+impl<A> Handler<ActorTerminated> for A
+where
+    A: Actor + Sized,
+{
+    fn handle(
+        &mut self,
+        msg: ActorTerminated,
+        context: &ActorContext<A>,
+    ) -> Result<ActorResult, Box<dyn Error>> {
+        return self.on_actor_terminated(context, msg);
+    }
+}
+
 impl<A> Handler<SystemStopMessage> for A
 where
     A: Actor + Sized,
@@ -110,3 +173,36 @@ where
         return Ok(ActorResult::Sleep(msg.duration));
     }
 }
+
+impl<M, A> Handler<RequestMessage<M>> for A
+where
+    A: RequestHandler<M> + Sized,
+    M: ActorRequest + 'static,
+{
+    fn handle(
+        &mut self,
+        msg: RequestMessage<M>,
+        context: &ActorContext<A>,
+    ) -> Result<ActorResult, Box<dyn Error>> {
+        let response = RequestHandler::handle(self, msg.msg, context)?;
+        // the requesting side may have already given up waiting, which is not an error for the actor
+        let _ = msg.reply_to.send(response);
+        return Ok(ActorResult::Ok);
+    }
+}
+
+impl<M, A> Handler<AsyncActorMessage<M>> for A
+where
+    A: AsyncHandler<M> + Sized,
+    M: BaseActorMessage + 'static,
+{
+    fn handle(
+        &mut self,
+        msg: AsyncActorMessage<M>,
+        context: &ActorContext<A>,
+    ) -> Result<ActorResult, Box<dyn Error>> {
+        let future = AsyncHandler::handle(self, msg.msg, context);
+        context.add_future(future);
+        return Ok(ActorResult::Ok);
+    }
+}
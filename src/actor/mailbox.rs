@@ -1,24 +1,62 @@
+use crate::actor::actor_address::ActorAddress;
 use crate::actor::actor_send_error::ActorSendError;
+use crate::actor::actor_state::ActorState;
 use crate::actor::handler::Handler;
+use crate::actor::mailbox_overflow_strategy::MailboxOverflowStrategy;
+use crate::actor::termination_handle::TerminationHandle;
+use crate::actor::termination_reason::TerminationReason;
+use crate::message::actor_kill_message::ActorKillMessage;
 use crate::message::actor_message::BaseActorMessage;
-use crate::message::envelope::MessageEnvelope;
+use crate::message::actor_restart_message::ActorRestartMessage;
+use crate::message::actor_stop_message::ActorStopMessage;
+use crate::message::actor_terminated::ActorTerminated;
+use crate::message::child_escalation_message::ChildEscalationMessage;
+use crate::message::dead_letter::DeadLetter;
+use crate::message::dead_letter_reason::DeadLetterReason;
+use crate::message::envelope::{MessageEnvelope, MessageEnvelopeTrait};
 use crate::prelude::{Actor, SerializedMessage};
+use crate::system::internal_actor_manager::InternalActorManager;
 use std::any::Any;
 use std::panic::UnwindSafe;
-use std::sync::atomic::{AtomicBool, Ordering};
-use std::sync::Arc;
-use std::time::Duration;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
 
 pub trait BaseMailbox: Send + Sync + UnwindSafe {
     fn send_serialized(&self, _msg: SerializedMessage);
+    fn send_stop(&self);
+    fn send_kill(&self);
+    fn send_restart(&self);
+    fn send_escalation(&self, child: ActorAddress);
+    fn send_terminated(&self, address: ActorAddress, reason: TerminationReason);
     fn as_any(&self) -> &dyn Any;
-    fn is_sleeping(&self) -> bool;
+    fn claim_wakeup(&self) -> bool;
+    fn get_state(&self) -> ActorState;
+    fn get_termination_handle(&self) -> TerminationHandle;
 }
 
 pub struct Mailbox<A> {
     pub is_stopped: Arc<AtomicBool>,
     pub is_sleeping: Arc<AtomicBool>,
+    /// set while the actor sleeps for a specific duration
+    pub sleeping_until: Arc<Mutex<Option<Instant>>>,
     pub msg_in: flume::Sender<MessageEnvelope<A>>,
+    /// only available for bounded priority mailboxes, holds a slot for every message in `msg_in` or in the priority queue of the executor
+    pub capacity_in: Option<flume::Sender<()>>,
+    /// unbounded lane for control messages, which is always handled before `msg_in`
+    pub control_in: flume::Sender<MessageEnvelope<A>>,
+    pub overflow_strategy: MailboxOverflowStrategy,
+    /// only available for [MailboxOverflowStrategy::DropOldest] to remove the oldest message
+    pub msg_out: Option<flume::Receiver<MessageEnvelope<A>>>,
+    /// resolved once the actor was removed from the system
+    pub termination_handle: TerminationHandle,
+    /// applied by the executor at the start of the next scheduling slice
+    pub message_throughput: Arc<AtomicUsize>,
+    /// amount of messages that the executor already moved from `msg_in` into its priority queue
+    pub prioritized_len: Arc<AtomicUsize>,
+    pub address: ActorAddress,
+    /// publishes messages that are dropped by the [MailboxOverflowStrategy] as dead letters
+    pub internal_actor_manager: Box<InternalActorManager>,
 }
 
 impl<A> BaseMailbox for Mailbox<A>
@@ -26,15 +64,53 @@ where
     A: Handler<SerializedMessage> + 'static,
 {
     fn send_serialized(&self, msg: SerializedMessage) {
-        self.msg_in.send(MessageEnvelope::new(msg)).unwrap();
+        let _ = self.send(msg);
+    }
+
+    fn send_stop(&self) {
+        let _ = self
+            .control_in
+            .send(MessageEnvelope::new(ActorStopMessage::new()));
+    }
+
+    fn send_kill(&self) {
+        let _ = self
+            .control_in
+            .send(MessageEnvelope::new(ActorKillMessage::new()));
+    }
+
+    fn send_restart(&self) {
+        let _ = self
+            .control_in
+            .send(MessageEnvelope::new(ActorRestartMessage::new()));
+    }
+
+    fn send_escalation(&self, child: ActorAddress) {
+        let _ = self
+            .control_in
+            .send(MessageEnvelope::new(ChildEscalationMessage::new(child)));
+    }
+
+    fn send_terminated(&self, address: ActorAddress, reason: TerminationReason) {
+        let _ = self
+            .control_in
+            .send(MessageEnvelope::new(ActorTerminated::new(address, reason)));
     }
 
     fn as_any(&self) -> &dyn Any {
         self
     }
 
-    fn is_sleeping(&self) -> bool {
-        self.is_sleeping.load(Ordering::Relaxed)
+    fn claim_wakeup(&self) -> bool {
+        return Mailbox::claim_wakeup(self);
+    }
+
+    fn get_state(&self) -> ActorState {
+        return Mailbox::get_state(self);
+    }
+
+    fn get_termination_handle(&self) -> TerminationHandle {
+        return self.termination_handle.clone();
     }
 }
 
@@ -45,8 +121,18 @@ where
     fn clone(&self) -> Self {
         Self {
             msg_in: self.msg_in.clone(),
+            capacity_in: self.capacity_in.clone(),
+            control_in: self.control_in.clone(),
+            overflow_strategy: self.overflow_strategy,
+            msg_out: self.msg_out.clone(),
             is_stopped: self.is_stopped.clone(),
             is_sleeping: self.is_sleeping.clone(),
+            sleeping_until: self.sleeping_until.clone(),
+            termination_handle: self.termination_handle.clone(),
+            message_throughput: self.message_throughput.clone(),
+            prioritized_len: self.prioritized_len.clone(),
+            address: self.address.clone(),
+            internal_actor_manager: self.internal_actor_manager.clone(),
         }
     }
 }
@@ -60,7 +146,16 @@ where
         A: Handler<M>,
         M: BaseActorMessage + 'static,
     {
-        let result = self.msg_in.send(MessageEnvelope::new(msg));
+        if self.is_overflow_handled(&msg) {
+            return self.send_with_overflow_strategy(MessageEnvelope::new(msg));
+        }
+        if let Some(capacity) = self.get_capacity(&msg) {
+            if capacity.send(()).is_err() {
+                return Err(ActorSendError::AlreadyStoppedError);
+            }
+        }
+        let sender = self.get_sender(&msg);
+        let result = sender.send(MessageEnvelope::new(msg));
         if result.is_ok() {
             return Ok(());
         }
@@ -73,22 +168,209 @@ where
         A: Handler<M>,
         M: BaseActorMessage + 'static,
     {
-        let result = self.msg_in.send_timeout(MessageEnvelope::new(msg), timeout);
+        if self.is_overflow_handled(&msg) {
+            return self.send_with_overflow_strategy(MessageEnvelope::new(msg));
+        }
+        if let Some(capacity) = self.get_capacity(&msg) {
+            if capacity.send_timeout((), timeout).is_err() {
+                return Err(ActorSendError::TimeoutError);
+            }
+        }
+        let sender = self.get_sender(&msg);
+        let result = sender.send_timeout(MessageEnvelope::new(msg), timeout);
         if result.is_ok() {
             return Ok(());
         }
         return Err(ActorSendError::TimeoutError);
     }
 
+    pub async fn send_async<M>(&self, msg: M) -> Result<(), ActorSendError>
+    where
+        A: Handler<M>,
+        M: BaseActorMessage + 'static,
+    {
+        if self.is_overflow_handled(&msg) {
+            return self.send_with_overflow_strategy(MessageEnvelope::new(msg));
+        }
+        if let Some(capacity) = self.get_capacity(&msg) {
+            if capacity.send_async(()).await.is_err() {
+                return Err(ActorSendError::AlreadyStoppedError);
+            }
+        }
+        let sender = self.get_sender(&msg);
+        let result = sender.send_async(MessageEnvelope::new(msg)).await;
+        if result.is_ok() {
+            return Ok(());
+        }
+
+        return Err(ActorSendError::AlreadyStoppedError);
+    }
+
+    /// Marks a sleeping mailbox as awake
+    /// Returns `true` if the mailbox was sleeping, in which case the caller is responsible to wake up the actor
+    pub fn claim_wakeup(&self) -> bool {
+        return self.is_sleeping.swap(false, Ordering::SeqCst);
+    }
+
+    /// never blocks, fails with [ActorSendError::MailboxFullError] if a bounded mailbox is full
+    pub fn try_send<M>(&self, msg: M) -> Result<(), ActorSendError>
+    where
+        A: Handler<M>,
+        M: BaseActorMessage + 'static,
+    {
+        let result = match self.get_capacity(&msg) {
+            Some(capacity) => self.try_send_with_capacity(capacity, MessageEnvelope::new(msg)),
+            None => self.get_sender(&msg).try_send(MessageEnvelope::new(msg)),
+        };
+        return match result {
+            Ok(()) => Ok(()),
+            Err(flume::TrySendError::Full(_)) => Err(ActorSendError::MailboxFullError),
+            Err(flume::TrySendError::Disconnected(_)) => Err(ActorSendError::AlreadyStoppedError),
+        };
+    }
+
     pub fn is_sleeping(&self) -> bool {
-        self.is_sleeping.load(Ordering::Relaxed)
+        return self.is_sleeping.load(Ordering::SeqCst);
+    }
+
+    pub fn get_sleeping_until(&self) -> Option<Instant> {
+        return *self.sleeping_until.lock().unwrap();
     }
 
     pub fn is_stopped(&self) -> bool {
         self.is_stopped.load(Ordering::Relaxed)
     }
 
+    pub fn get_state(&self) -> ActorState {
+        if self.is_stopped() {
+            return ActorState::Stopped;
+        }
+        let sleeping_until = self.get_sleeping_until();
+        if sleeping_until.is_some() {
+            return ActorState::Sleeping(
+                sleeping_until
+                    .unwrap()
+                    .saturating_duration_since(Instant::now()),
+            );
+        }
+        if self.is_sleeping() {
+            return ActorState::Inactive;
+        }
+        return ActorState::Running;
+    }
+
+    /// includes messages that were already moved into the priority queue of the executor
     pub fn len(&self) -> usize {
-        return self.msg_in.len();
+        return self.msg_in.len() + self.prioritized_len.load(Ordering::Relaxed);
+    }
+
+    pub fn capacity(&self) -> Option<usize> {
+        return match &self.capacity_in {
+            Some(capacity) => capacity.capacity(),
+            None => self.msg_in.capacity(),
+        };
+    }
+
+    pub fn is_full(&self) -> bool {
+        return match self.capacity() {
+            Some(capacity) => self.len() >= capacity,
+            None => false,
+        };
+    }
+
+    fn is_overflow_handled<M>(&self, msg: &M) -> bool
+    where
+        M: BaseActorMessage,
+    {
+        return self.overflow_strategy != MailboxOverflowStrategy::Block
+            && !msg.is_control_message();
+    }
+
+    fn send_with_overflow_strategy(
+        &self,
+        mut envelope: MessageEnvelope<A>,
+    ) -> Result<(), ActorSendError> {
+        // `msg_out` keeps the channel connected, even if the executor is gone
+        if self.is_stopped() {
+            return Err(ActorSendError::AlreadyStoppedError);
+        }
+        loop {
+            let result = match &self.capacity_in {
+                Some(capacity) => self.try_send_with_capacity(capacity, envelope),
+                None => self.msg_in.try_send(envelope),
+            };
+            let full = match result {
+                Ok(()) => return Ok(()),
+                Err(flume::TrySendError::Disconnected(_)) => {
+                    return Err(ActorSendError::AlreadyStoppedError)
+                }
+                Err(flume::TrySendError::Full(full)) => full,
+            };
+            match self.overflow_strategy {
+                MailboxOverflowStrategy::DropOldest => {
+                    let oldest = self.msg_out.as_ref().unwrap().try_recv();
+                    if let Ok(oldest) = oldest {
+                        self.send_dead_letter(oldest.get_type_name());
+                        // the slot of the oldest message is handed over to the new one
+                        if self.capacity_in.is_some() {
+                            return self
+                                .msg_in
+                                .send(full)
+                                .map_err(|_| ActorSendError::AlreadyStoppedError);
+                        }
+                    }
+                    envelope = full;
+                }
+                MailboxOverflowStrategy::DropNewest => {
+                    self.send_dead_letter(full.get_type_name());
+                    return Ok(());
+                }
+                _ => return Err(ActorSendError::MailboxFullError),
+            }
+        }
+    }
+
+    /// reserves a slot before the message is sent to the unbounded `msg_in`
+    fn try_send_with_capacity(
+        &self,
+        capacity: &flume::Sender<()>,
+        envelope: MessageEnvelope<A>,
+    ) -> Result<(), flume::TrySendError<MessageEnvelope<A>>> {
+        return match capacity.try_send(()) {
+            Ok(()) => self.msg_in.try_send(envelope),
+            Err(flume::TrySendError::Full(_)) => Err(flume::TrySendError::Full(envelope)),
+            Err(flume::TrySendError::Disconnected(_)) => {
+                Err(flume::TrySendError::Disconnected(envelope))
+            }
+        };
+    }
+
+    fn send_dead_letter(&self, message_type: &'static str) {
+        self.internal_actor_manager
+            .send_dead_letter(DeadLetter::new(
+                self.address.clone(),
+                message_type,
+                DeadLetterReason::MailboxFull,
+            ));
+    }
+
+    fn get_capacity<M>(&self, msg: &M) -> Option<&flume::Sender<()>>
+    where
+        M: BaseActorMessage,
+    {
+        if msg.is_control_message() {
+            return None;
+        }
+        return self.capacity_in.as_ref();
+    }
+
+    fn get_sender<M>(&self, msg: &M) -> &flume::Sender<MessageEnvelope<A>>
+    where
+        M: BaseActorMessage,
+    {
+        if msg.is_control_message() {
+            return &self.control_in;
+        }
+        return &self.msg_in;
     }
 }
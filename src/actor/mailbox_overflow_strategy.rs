@@ -0,0 +1,24 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(PartialEq, Clone, Copy, Debug, Serialize, Deserialize)]
+/// Defines what happens to a message that is sent to an actor with a full, bounded mailbox
+///
+/// Internal control messages are never affected, they use a separate lane that is always unbounded
+pub enum MailboxOverflowStrategy {
+    /// the sender blocks until there's space in the mailbox, or until the timeout of [ActorWrapper.send_timeout](../prelude/struct.ActorWrapper.html#method.send_timeout) is reached
+    Block,
+    /// the new message is published as a [DeadLetter](../prelude/struct.DeadLetter.html) and the send is treated as successful
+    DropNewest,
+    /// the oldest message in the mailbox is published as a [DeadLetter](../prelude/struct.DeadLetter.html) to make room for the new message
+    DropOldest,
+    /// the send fails immediately with [ActorSendError::MailboxFullError](../prelude/enum.ActorSendError.html#variant.MailboxFullError)
+    Reject,
+    /// same as `Reject`, but the message is additionally published as a [DeadLetter](../prelude/struct.DeadLetter.html)
+    DeadLetter,
+}
+
+impl Default for MailboxOverflowStrategy {
+    fn default() -> Self {
+        return MailboxOverflowStrategy::Block;
+    }
+}
@@ -1,26 +1,51 @@
 pub mod actor;
 pub mod actor_address;
+pub mod actor_address_parse_error;
 pub mod actor_builder;
 pub mod actor_config;
 pub mod actor_factory;
+pub mod actor_info;
 pub mod actor_panic_source;
 pub mod actor_result;
 pub mod actor_send_error;
 pub mod actor_state;
 pub mod actor_wrapper;
+pub mod async_handler;
 pub mod context;
 pub mod executor;
+pub mod future_waker;
 pub mod handler;
 pub mod mailbox;
+pub mod mailbox_overflow_strategy;
+pub mod recipient;
+pub mod request_handler;
+pub mod restart_policy;
+pub mod supervision_strategy;
+pub mod termination_handle;
+pub mod termination_reason;
 
 pub mod prelude {
     pub use crate::actor::actor::Actor;
+    pub use crate::actor::actor_address::ActorAddress;
+    pub use crate::actor::actor_address_parse_error::ActorAddressParseError;
     pub use crate::actor::actor_builder::ActorBuilder;
     pub use crate::actor::actor_factory::ActorFactory;
+    pub use crate::actor::actor_info::ActorInfo;
     pub use crate::actor::actor_panic_source::ActorPanicSource;
     pub use crate::actor::actor_result::ActorResult;
     pub use crate::actor::actor_send_error::ActorSendError;
+    pub use crate::actor::actor_state::ActorState;
     pub use crate::actor::actor_wrapper::ActorWrapper;
+    pub use crate::actor::async_handler::ActorFuture;
+    pub use crate::actor::async_handler::AsyncHandler;
     pub use crate::actor::context::ActorContext;
     pub use crate::actor::handler::Handler;
+    pub use crate::actor::mailbox_overflow_strategy::MailboxOverflowStrategy;
+    pub use crate::actor::recipient::Recipient;
+    pub use crate::actor::request_handler::RequestHandler;
+    pub use crate::actor::restart_policy::RestartLimitAction;
+    pub use crate::actor::restart_policy::RestartPolicy;
+    pub use crate::actor::supervision_strategy::SupervisionStrategy;
+    pub use crate::actor::termination_handle::TerminationHandle;
+    pub use crate::actor::termination_reason::TerminationReason;
 }
@@ -0,0 +1,193 @@
+use crate::actor::actor_address::ActorAddress;
+use crate::actor::actor_send_error::ActorSendError;
+use crate::actor::actor_wrapper::ActorWrapper;
+use crate::actor::handler::Handler;
+use crate::message::actor_message::BaseActorMessage;
+use crate::system::timer_handle::TimerHandle;
+use std::fmt::{Debug, Formatter};
+use std::panic::UnwindSafe;
+use std::sync::Arc;
+use std::time::Duration;
+
+trait RecipientTrait<M>: Send + Sync
+where
+    M: BaseActorMessage + 'static,
+{
+    fn send(&self, msg: M) -> Result<(), ActorSendError>;
+    fn try_send(&self, msg: M) -> Result<(), ActorSendError>;
+    fn send_timeout(&self, msg: M, timeout: Duration) -> Result<(), ActorSendError>;
+    fn send_after(&self, msg: M, delay: Duration) -> Result<TimerHandle, ActorSendError>;
+    fn get_address(&self) -> &ActorAddress;
+    fn get_mailbox_size(&self) -> usize;
+}
+
+impl<A, M> RecipientTrait<M> for ActorWrapper<A>
+where
+    A: Handler<M> + 'static,
+    M: BaseActorMessage + 'static,
+{
+    fn send(&self, msg: M) -> Result<(), ActorSendError> {
+        return ActorWrapper::send(self, msg);
+    }
+
+    fn try_send(&self, msg: M) -> Result<(), ActorSendError> {
+        return ActorWrapper::try_send(self, msg);
+    }
+
+    fn send_timeout(&self, msg: M, timeout: Duration) -> Result<(), ActorSendError> {
+        return ActorWrapper::send_timeout(self, msg, timeout);
+    }
+
+    fn send_after(&self, msg: M, delay: Duration) -> Result<TimerHandle, ActorSendError> {
+        return ActorWrapper::send_after(self, msg, delay);
+    }
+
+    fn get_address(&self) -> &ActorAddress {
+        return ActorWrapper::get_address(self);
+    }
+
+    fn get_mailbox_size(&self) -> usize {
+        return ActorWrapper::get_mailbox_size(self);
+    }
+}
+
+/// Type-erased handle that is able to send messages of type `M` to any actor that implements `Handler<M>`
+///
+/// Created through [ActorWrapper.get_recipient](../prelude/struct.ActorWrapper.html#method.get_recipient) or `Recipient::from(actor_wrapper)`
+///
+/// # Examples
+///
+/// ```rust
+/// use tyra::prelude::*;
+/// use std::error::Error;
+/// use std::time::Duration;
+///
+/// struct PriceUpdate {
+///     price: usize,
+/// }
+/// impl ActorMessage for PriceUpdate {}
+///
+/// struct Logger {}
+/// impl Actor for Logger {}
+/// impl Handler<PriceUpdate> for Logger {
+///     fn handle(&mut self, _msg: PriceUpdate, _context: &ActorContext<Self>) -> Result<ActorResult, Box<dyn Error>> {
+///         Ok(ActorResult::Ok)
+///     }
+/// }
+///
+/// struct Trader {}
+/// impl Actor for Trader {}
+/// impl Handler<PriceUpdate> for Trader {
+///     fn handle(&mut self, msg: PriceUpdate, context: &ActorContext<Self>) -> Result<ActorResult, Box<dyn Error>> {
+///         if msg.price == 2 {
+///             context.system.stop(Duration::from_millis(1000));
+///         }
+///         Ok(ActorResult::Ok)
+///     }
+/// }
+///
+/// struct LoggerFactory {}
+/// impl ActorFactory<Logger> for LoggerFactory {
+///     fn new_actor(&mut self, _context: ActorContext<Logger>) -> Result<Logger, Box<dyn Error>> {
+///         Ok(Logger {})
+///     }
+/// }
+///
+/// struct TraderFactory {}
+/// impl ActorFactory<Trader> for TraderFactory {
+///     fn new_actor(&mut self, _context: ActorContext<Trader>) -> Result<Trader, Box<dyn Error>> {
+///         Ok(Trader {})
+///     }
+/// }
+///
+/// #[ntest::timeout(10000)]
+/// fn main() {
+///     let actor_config = TyraConfig::new().unwrap();
+///     let actor_system = ActorSystem::new(actor_config);
+///     let logger = actor_system.builder().spawn("logger", LoggerFactory {}).unwrap();
+///     let trader = actor_system.builder().spawn("trader", TraderFactory {}).unwrap();
+///
+///     let recipients: Vec<Recipient<PriceUpdate>> = vec![logger.get_recipient(), Recipient::from(trader)];
+///     for (price, recipient) in recipients.iter().enumerate() {
+///         recipient.send(PriceUpdate { price: price + 1 }).unwrap();
+///     }
+///     assert_eq!(recipients[1].get_address().actor, "trader");
+///
+///     std::process::exit(actor_system.await_shutdown());
+/// }
+/// ```
+pub struct Recipient<M>
+where
+    M: BaseActorMessage + 'static,
+{
+    actor: Arc<dyn RecipientTrait<M>>,
+}
+
+impl<M> UnwindSafe for Recipient<M> where M: BaseActorMessage + 'static {}
+
+impl<M> Recipient<M>
+where
+    M: BaseActorMessage + 'static,
+{
+    /// Same as [ActorWrapper.send](../prelude/struct.ActorWrapper.html#method.send)
+    pub fn send(&self, msg: M) -> Result<(), ActorSendError> {
+        return self.actor.send(msg);
+    }
+
+    /// Same as [ActorWrapper.try_send](../prelude/struct.ActorWrapper.html#method.try_send)
+    pub fn try_send(&self, msg: M) -> Result<(), ActorSendError> {
+        return self.actor.try_send(msg);
+    }
+
+    /// Same as [ActorWrapper.send_timeout](../prelude/struct.ActorWrapper.html#method.send_timeout)
+    pub fn send_timeout(&self, msg: M, timeout: Duration) -> Result<(), ActorSendError> {
+        return self.actor.send_timeout(msg, timeout);
+    }
+
+    /// Same as [ActorWrapper.send_after](../prelude/struct.ActorWrapper.html#method.send_after)
+    pub fn send_after(&self, msg: M, delay: Duration) -> Result<TimerHandle, ActorSendError> {
+        return self.actor.send_after(msg, delay);
+    }
+
+    /// Returns a reference to the address of the actor
+    pub fn get_address(&self) -> &ActorAddress {
+        return self.actor.get_address();
+    }
+
+    /// Returns the amount of messages that are currently in the mailbox of the actor
+    pub fn get_mailbox_size(&self) -> usize {
+        return self.actor.get_mailbox_size();
+    }
+}
+
+impl<A, M> From<ActorWrapper<A>> for Recipient<M>
+where
+    A: Handler<M> + 'static,
+    M: BaseActorMessage + 'static,
+{
+    fn from(actor: ActorWrapper<A>) -> Self {
+        Self {
+            actor: Arc::new(actor),
+        }
+    }
+}
+
+impl<M> Clone for Recipient<M>
+where
+    M: BaseActorMessage + 'static,
+{
+    fn clone(&self) -> Self {
+        Self {
+            actor: self.actor.clone(),
+        }
+    }
+}
+
+impl<M> Debug for Recipient<M>
+where
+    M: BaseActorMessage + 'static,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Recipient({})", self.get_address())
+    }
+}
@@ -0,0 +1,47 @@
+use crate::actor::actor::Actor;
+use crate::actor::context::ActorContext;
+use crate::message::actor_request::ActorRequest;
+use std::error::Error;
+
+/// Defines which [ActorRequest](../prelude/trait.ActorRequest.html) is supported per [Actor] and how it is answered
+///
+/// Requests are sent through [ActorWrapper.ask](../prelude/struct.ActorWrapper.html#method.ask) and the returned response is delivered back to the sender
+///
+/// If the implementation returns an error, [on_error](../prelude/trait.Actor.html#method.on_error) is triggered and the sender receives `ActorSendError::NoResponseError`
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```rust
+/// use std::error::Error;
+/// use tyra::prelude::{ActorContext, Actor, ActorMessage, ActorRequest, RequestHandler};
+///
+/// struct TestActor {
+///     count: usize,
+/// }
+/// impl Actor for TestActor {}
+///
+/// struct GetCount {}
+/// impl ActorMessage for GetCount {}
+/// impl ActorRequest for GetCount {
+///     type Response = usize;
+/// }
+///
+/// impl RequestHandler<GetCount> for TestActor {
+///     fn handle(&mut self, _msg: GetCount, _context: &ActorContext<Self>) -> Result<usize, Box<dyn Error>> {
+///         Ok(self.count)
+///     }
+/// }
+/// ```
+pub trait RequestHandler<M>
+where
+    Self: Actor + Sized,
+    M: ActorRequest,
+{
+    fn handle(
+        &mut self,
+        msg: M,
+        context: &ActorContext<Self>,
+    ) -> Result<M::Response, Box<dyn Error>>;
+}
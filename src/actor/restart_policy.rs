@@ -0,0 +1,145 @@
+use serde::{Deserialize, Serialize};
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hash, Hasher};
+use std::time::{Duration, Instant};
+
+#[derive(PartialEq, Clone, Copy, Debug, Serialize, Deserialize)]
+/// Defines what happens to an actor, after it reached the `max_restarts` of its [RestartPolicy]
+pub enum RestartLimitAction {
+    /// stop immediately and ignore any remaining messages in the mailbox
+    Stop,
+    /// stop immediately and escalate the failure to the parent, see [ActorResult::Escalate](../prelude/enum.ActorResult.html#variant.Escalate)
+    Escalate,
+}
+
+/// Defines how often and how fast an actor is restarted, see [ActorBuilder.set_restart_policy](../prelude/struct.ActorBuilder.html#method.set_restart_policy)
+///
+/// Per default actors are restarted immediately and without any limit
+///
+/// Durations are configured in milliseconds, missing keys fall back to the defaults, i.e.
+///
+/// ```rust
+/// use tyra::prelude::{RestartLimitAction, TyraConfig};
+/// use std::str::FromStr;
+/// use std::time::Duration;
+///
+/// let config = TyraConfig::from_str(r#"
+/// [actors."default/worker-*".restart_policy]
+/// max_restarts = 5
+/// initial_backoff_ms = 100
+/// on_limit_reached = "Escalate"
+/// "#).unwrap();
+///
+/// let restart_policy = config.get_actor_deployment_config("default", "worker-1").restart_policy.unwrap();
+/// assert_eq!(restart_policy.max_restarts, 5);
+/// assert_eq!(restart_policy.initial_backoff, Duration::from_millis(100));
+/// assert_eq!(restart_policy.restart_window, Duration::from_secs(60));
+/// assert_eq!(restart_policy.on_limit_reached, RestartLimitAction::Escalate);
+/// ```
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(default)]
+pub struct RestartPolicy {
+    /// maximum amount of restarts within `restart_window`, `0` disables the limit
+    pub max_restarts: usize,
+    #[serde(rename = "restart_window_ms", with = "duration_ms")]
+    pub restart_window: Duration,
+    /// delay before the first restart within `restart_window`, `Duration::ZERO` restarts immediately
+    #[serde(rename = "initial_backoff_ms", with = "duration_ms")]
+    pub initial_backoff: Duration,
+    #[serde(rename = "max_backoff_ms", with = "duration_ms")]
+    pub max_backoff: Duration,
+    /// factor applied to the backoff for every further restart within `restart_window`
+    pub backoff_multiplier: f64,
+    /// random deviation of the backoff, i.e. `0.2` results in a deviation of up to 20%
+    pub jitter: f64,
+    pub on_limit_reached: RestartLimitAction,
+}
+
+impl Default for RestartPolicy {
+    fn default() -> Self {
+        Self {
+            max_restarts: 0,
+            restart_window: Duration::from_secs(60),
+            initial_backoff: Duration::ZERO,
+            max_backoff: Duration::ZERO,
+            backoff_multiplier: 2.0,
+            jitter: 0.0,
+            on_limit_reached: RestartLimitAction::Stop,
+        }
+    }
+}
+
+impl RestartPolicy {
+    /// Creates a policy with exponential backoff that doubles with every restart and a jitter of 20%
+    ///
+    /// the actor is stopped after `max_restarts` within `restart_window`
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// use tyra::prelude::{RestartLimitAction, RestartPolicy};
+    /// use std::time::Duration;
+    ///
+    /// let mut policy = RestartPolicy::new(5, Duration::from_secs(60), Duration::from_millis(100), Duration::from_secs(10));
+    /// policy.on_limit_reached = RestartLimitAction::Escalate;
+    /// ```
+    pub fn new(
+        max_restarts: usize,
+        restart_window: Duration,
+        initial_backoff: Duration,
+        max_backoff: Duration,
+    ) -> Self {
+        Self {
+            max_restarts,
+            restart_window,
+            initial_backoff,
+            max_backoff,
+            backoff_multiplier: 2.0,
+            jitter: 0.2,
+            on_limit_reached: RestartLimitAction::Stop,
+        }
+    }
+
+    /// returns the delay before the next restart, based on the amount of previous restarts within `restart_window`
+    pub fn get_backoff(&self, previous_restarts: usize) -> Duration {
+        if self.initial_backoff.is_zero() {
+            return Duration::ZERO;
+        }
+        let exponent = previous_restarts.min(i32::MAX as usize) as i32;
+        let backoff = self.initial_backoff.as_secs_f64() * self.backoff_multiplier.powi(exponent);
+        let backoff = backoff.min(
+            self.max_backoff
+                .as_secs_f64()
+                .max(self.initial_backoff.as_secs_f64()),
+        );
+
+        let mut hasher = RandomState::new().build_hasher();
+        Instant::now().hash(&mut hasher);
+        let random = hasher.finish() as f64 / u64::MAX as f64;
+        let jitter = backoff * self.jitter * (random * 2.0 - 1.0);
+
+        return Duration::from_secs_f64((backoff + jitter).max(0.0));
+    }
+}
+
+/// (de)serializes durations as milliseconds, same as the other `_ms` settings of the config
+mod duration_ms {
+    use serde::{Deserialize, Deserializer, Serializer};
+    use std::time::Duration;
+
+    pub fn serialize<S>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        return serializer.serialize_u64(duration.as_millis() as u64);
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Duration, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        return Ok(Duration::from_millis(u64::deserialize(deserializer)?));
+    }
+}
@@ -0,0 +1,14 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(PartialEq, Clone, Copy, Debug, Serialize, Deserialize)]
+/// Defines how a parent reacts, if [on_panic](../prelude/trait.Actor.html#method.on_panic) or [on_error](../prelude/trait.Actor.html#method.on_error) of one of its children decides to fail
+///
+/// A child fails, if the hook returns [ActorResult::Restart](../prelude/enum.ActorResult.html#variant.Restart) or [ActorResult::Kill](../prelude/enum.ActorResult.html#variant.Kill)
+pub enum SupervisionStrategy {
+    /// only the failing child is restarted or killed
+    OneForOne,
+    /// all children of the parent are restarted or killed, if one of them fails
+    AllForOne,
+    /// the failing child is killed and the failure is escalated to the parent, see [ActorResult::Escalate](../prelude/enum.ActorResult.html#variant.Escalate)
+    Escalate,
+}
@@ -0,0 +1,74 @@
+use crate::actor::actor_send_error::ActorSendError;
+use crate::actor::termination_reason::TerminationReason;
+use std::sync::{Arc, Condvar, Mutex};
+use std::time::{Duration, Instant};
+
+/// Resolves once the actor has terminated and was removed from the [ActorSystem](../prelude/struct.ActorSystem.html)
+///
+/// Returned by [ActorWrapper.get_termination_handle](../prelude/struct.ActorWrapper.html#method.get_termination_handle) and [ActorSystem.stop_actor](../prelude/struct.ActorSystem.html#method.stop_actor)
+#[derive(Clone)]
+pub struct TerminationHandle {
+    termination: Arc<(Mutex<Option<TerminationReason>>, Condvar)>,
+}
+
+impl TerminationHandle {
+    pub(crate) fn new() -> Self {
+        Self {
+            termination: Arc::new((Mutex::new(None), Condvar::new())),
+        }
+    }
+
+    /// resolves all handles, only the first reason is kept
+    pub(crate) fn terminate(&self, reason: TerminationReason) {
+        let (termination_reason, condvar) = &*self.termination;
+        let mut termination_reason = termination_reason.lock().unwrap();
+        if termination_reason.is_none() {
+            *termination_reason = Some(reason);
+        }
+        condvar.notify_all();
+    }
+
+    /// Returns the [TerminationReason](../prelude/enum.TerminationReason.html) if the actor has already terminated
+    pub fn get_termination_reason(&self) -> Option<TerminationReason> {
+        let (termination_reason, _) = &*self.termination;
+        return *termination_reason.lock().unwrap();
+    }
+
+    /// Returns `true` if the actor has terminated
+    pub fn is_terminated(&self) -> bool {
+        return self.get_termination_reason().is_some();
+    }
+
+    /// Blocks until the actor has terminated
+    pub fn wait(&self) -> TerminationReason {
+        let (termination_reason, condvar) = &*self.termination;
+        let mut termination_reason = termination_reason.lock().unwrap();
+        loop {
+            if termination_reason.is_some() {
+                return termination_reason.unwrap();
+            }
+            termination_reason = condvar.wait(termination_reason).unwrap();
+        }
+    }
+
+    /// Blocks until the actor has terminated
+    /// Fails with [ActorSendError::TerminationTimeoutError](../prelude/enum.ActorSendError.html#variant.TerminationTimeoutError) if the actor did not terminate within `timeout`
+    pub fn wait_timeout(&self, timeout: Duration) -> Result<TerminationReason, ActorSendError> {
+        let started = Instant::now();
+        let (termination_reason, condvar) = &*self.termination;
+        let mut termination_reason = termination_reason.lock().unwrap();
+        loop {
+            if termination_reason.is_some() {
+                return Ok(termination_reason.unwrap());
+            }
+            let remaining = timeout.saturating_sub(started.elapsed());
+            if remaining.is_zero() {
+                return Err(ActorSendError::TerminationTimeoutError);
+            }
+            termination_reason = condvar
+                .wait_timeout(termination_reason, remaining)
+                .unwrap()
+                .0;
+        }
+    }
+}
@@ -0,0 +1,10 @@
+#[derive(PartialEq, Clone, Copy, Debug)]
+/// Information provided through [ActorTerminated](../prelude/struct.ActorTerminated.html) to distinguish why an actor terminated
+pub enum TerminationReason {
+    /// the actor stopped after all messages in its mailbox have been processed
+    Stopped,
+    /// the actor was stopped immediately and remaining messages in its mailbox were ignored
+    Killed,
+    /// the actor stopped during [pre_start](../prelude/trait.Actor.html#method.pre_start) or while being recreated during a restart
+    InitFailed,
+}
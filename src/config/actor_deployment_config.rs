@@ -0,0 +1,147 @@
+use crate::actor::actor_config::ActorConfig;
+use crate::actor::mailbox_overflow_strategy::MailboxOverflowStrategy;
+use crate::actor::restart_policy::RestartPolicy;
+use crate::actor::supervision_strategy::SupervisionStrategy;
+use serde::{Deserialize, Serialize};
+
+/// Overrides the configuration of all actors that match the `"<pool>/<name>"` pattern of the `[actors]` section, i.e.
+///
+/// ```toml
+/// [actors."default/hot-actor"]
+/// message_throughput = 100
+///
+/// [actors."default/worker-*"]
+/// mailbox_size = 1000
+/// mailbox_overflow_strategy = "DropOldest"
+/// ```
+///
+/// `*` matches any amount of characters and `?` matches exactly one character
+/// If multiple patterns match the same actor, settings of more specific patterns take precedence
+///
+/// Settings of the [ActorBuilder](../prelude/struct.ActorBuilder.html) are used as defaults and are overwritten by the config,
+/// unless they are forced through [ActorBuilder.set_forced_config](../prelude/struct.ActorBuilder.html#method.set_forced_config)
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct ActorDeploymentConfig {
+    /// moves the actor to a different thread_pool, the pattern is matched against the pool that is set in the code
+    pub pool_name: Option<String>,
+    pub mailbox_size: Option<usize>,
+    pub mailbox_overflow_strategy: Option<MailboxOverflowStrategy>,
+    pub message_throughput: Option<usize>,
+    pub sequential_async_handling: Option<bool>,
+    pub priority_mailbox: Option<bool>,
+    pub supervision_strategy: Option<SupervisionStrategy>,
+    pub restart_policy: Option<RestartPolicy>,
+    pub shutdown_phase: Option<usize>,
+}
+
+impl ActorDeploymentConfig {
+    /// Returns `true` if `pattern` matches the actor called `actor_name` on the `pool_name`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tyra::prelude::ActorDeploymentConfig;
+    ///
+    /// assert!(ActorDeploymentConfig::matches("default/worker-*", "default", "worker-1"));
+    /// assert!(ActorDeploymentConfig::matches("*/worker-?", "tyra", "worker-1"));
+    /// assert!(!ActorDeploymentConfig::matches("default/worker-?", "default", "worker-10"));
+    /// assert!(!ActorDeploymentConfig::matches("default/worker", "other", "worker"));
+    /// ```
+    pub fn matches(pattern: &str, pool_name: &str, actor_name: &str) -> bool {
+        let pattern: Vec<char> = pattern.chars().collect();
+        let target: Vec<char> = format!("{}/{}", pool_name, actor_name).chars().collect();
+
+        let mut p = 0;
+        let mut t = 0;
+        let mut last_wildcard = None;
+        while t < target.len() {
+            if p < pattern.len() && (pattern[p] == '?' || pattern[p] == target[t]) {
+                p += 1;
+                t += 1;
+            } else if p < pattern.len() && pattern[p] == '*' {
+                last_wildcard = Some((p, t));
+                p += 1;
+            } else if let Some((wildcard_p, wildcard_t)) = last_wildcard {
+                // let the last `*` consume one more character and retry
+                p = wildcard_p + 1;
+                t = wildcard_t + 1;
+                last_wildcard = Some((wildcard_p, t));
+            } else {
+                return false;
+            }
+        }
+        while p < pattern.len() && pattern[p] == '*' {
+            p += 1;
+        }
+        return p == pattern.len();
+    }
+
+    /// patterns without wildcards are the most specific, otherwise the amount of literal characters decides
+    pub(crate) fn get_specificity(pattern: &str) -> (bool, usize) {
+        let has_wildcard = pattern.contains(|c| c == '*' || c == '?');
+        let literals = pattern.chars().filter(|c| *c != '*' && *c != '?').count();
+        return (!has_wildcard, literals);
+    }
+
+    /// settings of `other` take precedence
+    pub(crate) fn merge(&mut self, other: &ActorDeploymentConfig) {
+        if other.pool_name.is_some() {
+            self.pool_name = other.pool_name.clone();
+        }
+        if other.mailbox_size.is_some() {
+            self.mailbox_size = other.mailbox_size;
+        }
+        if other.mailbox_overflow_strategy.is_some() {
+            self.mailbox_overflow_strategy = other.mailbox_overflow_strategy;
+        }
+        if other.message_throughput.is_some() {
+            self.message_throughput = other.message_throughput;
+        }
+        if other.sequential_async_handling.is_some() {
+            self.sequential_async_handling = other.sequential_async_handling;
+        }
+        if other.priority_mailbox.is_some() {
+            self.priority_mailbox = other.priority_mailbox;
+        }
+        if other.supervision_strategy.is_some() {
+            self.supervision_strategy = other.supervision_strategy;
+        }
+        if other.restart_policy.is_some() {
+            self.restart_policy = other.restart_policy.clone();
+        }
+        if other.shutdown_phase.is_some() {
+            self.shutdown_phase = other.shutdown_phase;
+        }
+    }
+
+    pub(crate) fn apply(&self, actor_config: &mut ActorConfig) {
+        if let Some(pool_name) = &self.pool_name {
+            actor_config.pool_name = pool_name.clone();
+        }
+        if let Some(mailbox_size) = self.mailbox_size {
+            actor_config.mailbox_size = mailbox_size;
+        }
+        if let Some(mailbox_overflow_strategy) = self.mailbox_overflow_strategy {
+            actor_config.mailbox_overflow_strategy = mailbox_overflow_strategy;
+        }
+        if let Some(message_throughput) = self.message_throughput {
+            actor_config.message_throughput = message_throughput;
+        }
+        if let Some(sequential_async_handling) = self.sequential_async_handling {
+            actor_config.sequential_async_handling = sequential_async_handling;
+        }
+        if let Some(priority_mailbox) = self.priority_mailbox {
+            actor_config.priority_mailbox = priority_mailbox;
+        }
+        if let Some(supervision_strategy) = self.supervision_strategy {
+            actor_config.supervision_strategy = supervision_strategy;
+        }
+        if let Some(restart_policy) = &self.restart_policy {
+            actor_config.restart_policy = restart_policy.clone();
+        }
+        if let Some(shutdown_phase) = self.shutdown_phase {
+            actor_config.shutdown_phase = shutdown_phase;
+        }
+    }
+}
@@ -0,0 +1,53 @@
+use thiserror::Error;
+
+/// Returned by [TyraConfig.validate](../prelude/struct.TyraConfig.html#method.validate) for every problem within the configuration
+#[derive(Error, Debug, PartialEq, Clone)]
+pub enum ConfigValidationError {
+    /// `general.name` is empty
+    #[error("general.name must not be empty")]
+    EmptySystemName,
+
+    /// `general.name` or the name of a thread-pool is not a [valid](../prelude/struct.ActorAddress.html#method.is_valid_name) part of an address
+    #[error("name {} must not contain whitespaces, control characters or any of '/', '@', ':', '?' and '#'", .name)]
+    InvalidName { name: String },
+
+    /// `general.default_message_throughput` is `0`, which would prevent actors from handling any message
+    #[error("general.default_message_throughput must be greater than 0")]
+    InvalidMessageThroughput,
+
+    /// pattern of the `[actors]` section is not in the format `"<pool>/<name>"`
+    #[error("actors.\"{}\" must be in the format \"<pool>/<name>\"", .pattern)]
+    InvalidActorPattern { pattern: String },
+
+    /// `message_throughput` of an `[actors]` section is `0`
+    #[error("actors.\"{}\".message_throughput must be greater than 0", .pattern)]
+    InvalidActorMessageThroughput { pattern: String },
+
+    /// a thread-pool that is required by the system is not configured
+    #[error("thread_pool.config.{} is required", .pool)]
+    MissingThreadPool { pool: String },
+
+    /// `actor_limit` of a thread-pool is too small for the actors that the system spawns on it
+    #[error("thread_pool.config.{}.actor_limit ({}) must be 0 or at least {}", .pool, .actor_limit, .required)]
+    InvalidActorLimit {
+        pool: String,
+        actor_limit: usize,
+        required: usize,
+    },
+
+    /// `threads_max` of a thread-pool is `0`
+    #[error("thread_pool.config.{}.threads_max must be greater than 0", .pool)]
+    InvalidThreadsMax { pool: String },
+
+    /// `threads_min` of a thread-pool is greater than its `threads_max`
+    #[error("thread_pool.config.{}.threads_min ({}) must not be greater than threads_max ({})", .pool, .threads_min, .threads_max)]
+    InvalidThreadsRange {
+        pool: String,
+        threads_min: usize,
+        threads_max: usize,
+    },
+
+    /// `threads_factor` of a thread-pool is not greater than `0`
+    #[error("thread_pool.config.{}.threads_factor ({}) must be greater than 0", .pool, .threads_factor)]
+    InvalidThreadsFactor { pool: String, threads_factor: f32 },
+}
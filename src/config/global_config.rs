@@ -6,4 +6,6 @@ pub struct GeneralConfig {
     pub default_mailbox_size: usize,
     pub default_message_throughput: usize,
     pub override_panic_hook: bool,
+    pub handle_signals: bool,
+    pub signal_graceful_termination_timeout_ms: u64,
 }
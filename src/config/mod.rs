@@ -1,8 +1,12 @@
+pub mod actor_deployment_config;
+pub mod config_validation_error;
 pub mod global_config;
 pub mod pool_config;
 pub mod tyra_config;
 
 pub mod prelude {
+    pub use crate::config::actor_deployment_config::ActorDeploymentConfig;
+    pub use crate::config::config_validation_error::ConfigValidationError;
     pub use crate::config::pool_config::ThreadPoolConfig;
     pub use crate::config::tyra_config::TyraConfig;
 }
@@ -1,3 +1,7 @@
+use crate::actor::actor_address::ActorAddress;
+use crate::config::config_validation_error::ConfigValidationError;
+use crate::config::tyra_config::INTERNAL_POOL;
+use crate::system::internal_actor_manager::INTERNAL_ACTOR_COUNT;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
@@ -35,6 +39,65 @@ impl ThreadPoolConfig {
             threads_factor,
         }
     }
+
+    /// Returns every problem of the configuration for the thread-pool called `pool_name`
+    ///
+    /// The `actor_limit` of the internal `tyra` thread-pool has to leave room for the internal actors of the system
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tyra::prelude::{ThreadPoolConfig, ConfigValidationError};
+    ///
+    /// assert!(ThreadPoolConfig::new(0, 1, 2, 1.0).validate("test").is_ok());
+    ///
+    /// let errors = ThreadPoolConfig::new(0, 3, 2, 0.0).validate("test").err().unwrap();
+    /// assert_eq!(errors.len(), 2);
+    /// assert!(errors.contains(&ConfigValidationError::InvalidThreadsFactor { pool: String::from("test"), threads_factor: 0.0 }));
+    ///
+    /// let errors = ThreadPoolConfig::new(0, 1, 2, 1.0).validate("te/st").err().unwrap();
+    /// assert_eq!(errors, vec![ConfigValidationError::InvalidName { name: String::from("te/st") }]);
+    /// ```
+    pub fn validate(&self, pool_name: &str) -> Result<(), Vec<ConfigValidationError>> {
+        let mut errors = Vec::new();
+        if !ActorAddress::is_valid_name(pool_name) {
+            errors.push(ConfigValidationError::InvalidName {
+                name: String::from(pool_name),
+            });
+        }
+        if pool_name == INTERNAL_POOL
+            && self.actor_limit != 0
+            && self.actor_limit < INTERNAL_ACTOR_COUNT
+        {
+            errors.push(ConfigValidationError::InvalidActorLimit {
+                pool: String::from(pool_name),
+                actor_limit: self.actor_limit,
+                required: INTERNAL_ACTOR_COUNT,
+            });
+        }
+        if self.threads_max == 0 {
+            errors.push(ConfigValidationError::InvalidThreadsMax {
+                pool: String::from(pool_name),
+            });
+        }
+        if self.threads_min > self.threads_max {
+            errors.push(ConfigValidationError::InvalidThreadsRange {
+                pool: String::from(pool_name),
+                threads_min: self.threads_min,
+                threads_max: self.threads_max,
+            });
+        }
+        if !(self.threads_factor > 0.0) {
+            errors.push(ConfigValidationError::InvalidThreadsFactor {
+                pool: String::from(pool_name),
+                threads_factor: self.threads_factor,
+            });
+        }
+        if errors.is_empty() {
+            return Ok(());
+        }
+        return Err(errors);
+    }
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -1,26 +1,51 @@
+use crate::actor::actor_address::ActorAddress;
+use crate::config::actor_deployment_config::ActorDeploymentConfig;
+use crate::config::config_validation_error::ConfigValidationError;
 use crate::config::global_config::GeneralConfig;
 use crate::config::pool_config::PoolConfig;
+use std::collections::HashMap;
 use std::path::Path;
+use std::str::FromStr;
 
-use config::{Config, ConfigError, File, FileFormat};
+use config::builder::DefaultState;
+use config::{Config, ConfigBuilder, ConfigError, Environment, File, FileFormat};
 use serde::{Deserialize, Serialize};
 
 pub const DEFAULT_POOL: &str = "default";
+/// thread-pool of the internal actors of the system
+pub const INTERNAL_POOL: &str = "tyra";
+/// optional config file that is loaded by [TyraConfig::new]
+pub const DEFAULT_CONFIG_PATH: &str = "config/tyra.toml";
+/// prefix of all environment variables that override the config
+pub const ENV_PREFIX: &str = "TYRA";
+/// separates the nested keys of the config within environment variables
+pub const ENV_SEPARATOR: &str = "__";
 
 /// See [default.toml](https://github.com/sers-dev/tyra/blob/master/src/config/default.toml) for documentation of all configurations & their defaults
+///
+/// Every configuration can be overwritten through environment variables, which take precedence over config files
+/// Variables are prefixed with `TYRA_` and nested keys are separated by `__`, i.e.
+///
+/// - `TYRA_GENERAL__DEFAULT_MAILBOX_SIZE=1` overrides `general.default_mailbox_size`
+/// - `TYRA_THREAD_POOL__CONFIG__DEFAULT__THREADS_MAX=4` overrides `thread_pool.config.default.threads_max`
+///
+/// Keys are case insensitive, therefore thread-pools that are configured through environment variables need to have lowercase names
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct TyraConfig {
     pub general: GeneralConfig,
     pub thread_pool: PoolConfig,
+    /// per actor overrides, keyed by `"<pool>/<name>"` patterns, see [ActorDeploymentConfig](../prelude/struct.ActorDeploymentConfig.html)
+    #[serde(default)]
+    pub actors: HashMap<String, ActorDeploymentConfig>,
 }
 
 impl TyraConfig {
     /// Required for [ActorSystem.new](../prelude/struct.ActorSystem.html#method.new)
     ///
     /// Loads default config from [default.toml](https://github.com/sers-dev/tyra/blob/master/src/config/default.toml)
-    /// Overwrites defaults through environment variables. Replace toml `.` with `_CONFIG_`, i.e. `TYRACTORSAUR_GENERAL_CONFIG_DEFAULT_MAILBOX_SIZE=1`
+    /// Overwrites defaults through the optional `./config/tyra.toml` and afterwards through environment variables, see [TyraConfig](../prelude/struct.TyraConfig.html)
     ///
-    /// Replaces `$HOSTNAME` with the actual hostname of the system for the `TYRACTORSAUR_GENERAL_CONFIG_NAME`
+    /// Replaces `$HOSTNAME` with the actual hostname of the system for the `general.name`
     ///
     /// # Examples
     ///
@@ -32,24 +57,223 @@ impl TyraConfig {
     ///
     /// let mut config = TyraConfig::new().unwrap();
     /// config.general.name = String::from("HelloWorld");
+    ///
+    /// std::env::set_var("TYRA_GENERAL__DEFAULT_MAILBOX_SIZE", "7");
+    /// std::env::set_var("TYRA_THREAD_POOL__CONFIG__DEFAULT__THREADS_MAX", "4");
+    /// let config = TyraConfig::new().unwrap();
+    /// assert_eq!(config.general.default_mailbox_size, 7);
+    /// assert_eq!(config.thread_pool.config.get("default").unwrap().threads_max, 4);
     /// ```
     pub fn new() -> Result<Self, ConfigError> {
+        let mut config = Self::get_default_builder();
+        let path = Path::new(DEFAULT_CONFIG_PATH);
+        if path.exists() {
+            config = config.add_source(File::from(path));
+        }
+        return Self::build(config);
+    }
+
+    /// Same as [new](#method.new), but loads the config file from `path` instead of `./config/tyra.toml`
+    ///
+    /// Fails if the file does not exist
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tyra::prelude::TyraConfig;
+    ///
+    /// let path = std::env::temp_dir().join("tyra-from-file.toml");
+    /// std::fs::write(&path, "[general]\nname = \"HelloWorld\"").unwrap();
+    /// let config = TyraConfig::from_file(&path).unwrap();
+    /// assert_eq!(config.general.name, "HelloWorld");
+    ///
+    /// assert!(TyraConfig::from_file("does/not/exist.toml").is_err());
+    /// ```
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self, ConfigError> {
+        let config =
+            Self::get_default_builder().add_source(File::from(path.as_ref()).required(true));
+        return Self::build(config);
+    }
+
+    fn get_default_builder() -> ConfigBuilder<DefaultState> {
         let default: &str = std::include_str!("default.toml");
+        return Config::builder().add_source(File::from_str(default, FileFormat::Toml));
+    }
 
-        let mut config = Config::builder();
+    fn build(config: ConfigBuilder<DefaultState>) -> Result<Self, ConfigError> {
+        let config = config.add_source(
+            Environment::with_prefix(ENV_PREFIX)
+                .prefix_separator("_")
+                .separator(ENV_SEPARATOR)
+                .try_parsing(true),
+        );
 
-        config = config.add_source(File::from_str(default, FileFormat::Toml));
-        let path = Path::new("config/tyra.toml");
-        if path.exists() {
-            config = config.add_source(File::from(Path::new("config/tyra.toml")));
+        let conf = config.build()?;
+        let mut parsed: TyraConfig = conf.try_deserialize()?;
+        if parsed.general.name == "$HOSTNAME" {
+            let hostname = hostname::get().map_err(|e| ConfigError::Foreign(Box::new(e)))?;
+            parsed.general.name = hostname.to_string_lossy().into_owned();
         }
 
-        let conf = config.build().expect("Could not fetch Config");
-        let mut parsed: TyraConfig = conf.try_deserialize().expect("Could not parse Config");
-        if parsed.general.name == "$HOSTNAME" {
-            parsed.general.name = String::from(hostname::get().unwrap().to_str().unwrap());
+        let result = parsed.validate();
+        if result.is_err() {
+            let errors: Vec<String> = result
+                .unwrap_err()
+                .iter()
+                .map(|error| error.to_string())
+                .collect();
+            return Err(ConfigError::Message(errors.join(", ")));
         }
+        return Ok(parsed);
+    }
 
-        Ok(parsed)
+    /// Returns every problem of the configuration
+    ///
+    /// Called by [new](#method.new), [from_file](#method.from_file), [from_str](#method.from_str) and [ActorSystem::try_new](../prelude/struct.ActorSystem.html#method.try_new)
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tyra::prelude::{TyraConfig, ThreadPoolConfig, ConfigValidationError};
+    ///
+    /// let mut config = TyraConfig::new().unwrap();
+    /// assert!(config.validate().is_ok());
+    ///
+    /// config.general.name = String::new();
+    /// config.thread_pool.config.remove("tyra");
+    /// config.thread_pool.config.insert(String::from("default"), ThreadPoolConfig::new(0, 3, 2, 1.0));
+    /// config.thread_pool.config.insert(String::from("my pool"), ThreadPoolConfig::new(0, 1, 1, 1.0));
+    ///
+    /// let errors = config.validate().err().unwrap();
+    /// assert_eq!(errors, vec![
+    ///     ConfigValidationError::EmptySystemName,
+    ///     ConfigValidationError::MissingThreadPool { pool: String::from("tyra") },
+    ///     ConfigValidationError::InvalidThreadsRange { pool: String::from("default"), threads_min: 3, threads_max: 2 },
+    ///     ConfigValidationError::InvalidName { name: String::from("my pool") },
+    /// ]);
+    /// ```
+    pub fn validate(&self) -> Result<(), Vec<ConfigValidationError>> {
+        let mut errors = Vec::new();
+        if self.general.name.is_empty() {
+            errors.push(ConfigValidationError::EmptySystemName);
+        } else if !ActorAddress::is_valid_name(&self.general.name) {
+            errors.push(ConfigValidationError::InvalidName {
+                name: self.general.name.clone(),
+            });
+        }
+        if self.general.default_message_throughput == 0 {
+            errors.push(ConfigValidationError::InvalidMessageThroughput);
+        }
+        for pool in [DEFAULT_POOL, INTERNAL_POOL] {
+            if !self.thread_pool.config.contains_key(pool) {
+                errors.push(ConfigValidationError::MissingThreadPool {
+                    pool: String::from(pool),
+                });
+            }
+        }
+
+        let mut patterns: Vec<&String> = self.actors.keys().collect();
+        patterns.sort();
+        for pattern in patterns {
+            if pattern.matches('/').count() != 1 {
+                errors.push(ConfigValidationError::InvalidActorPattern {
+                    pattern: pattern.clone(),
+                });
+            }
+            if self.actors.get(pattern).unwrap().message_throughput == Some(0) {
+                errors.push(ConfigValidationError::InvalidActorMessageThroughput {
+                    pattern: pattern.clone(),
+                });
+            }
+        }
+
+        let mut pools: Vec<&String> = self.thread_pool.config.keys().collect();
+        pools.sort();
+        for pool in pools {
+            let result = self.thread_pool.config.get(pool).unwrap().validate(pool);
+            if result.is_err() {
+                errors.extend(result.unwrap_err());
+            }
+        }
+
+        if errors.is_empty() {
+            return Ok(());
+        }
+        return Err(errors);
+    }
+
+    /// Merges all `[actors]` sections that match the actor called `actor_name` on the `pool_name`
+    ///
+    /// Settings of more specific patterns take precedence, see [ActorDeploymentConfig](../prelude/struct.ActorDeploymentConfig.html)
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tyra::prelude::TyraConfig;
+    /// use std::str::FromStr;
+    ///
+    /// let config = TyraConfig::from_str(r#"
+    /// [actors."*/*"]
+    /// mailbox_size = 10
+    /// message_throughput = 5
+    ///
+    /// [actors."default/worker-*"]
+    /// mailbox_size = 100
+    ///
+    /// [actors."default/worker-1"]
+    /// message_throughput = 1
+    /// "#).unwrap();
+    ///
+    /// let deployment = config.get_actor_deployment_config("default", "worker-1");
+    /// assert_eq!(deployment.mailbox_size, Some(100));
+    /// assert_eq!(deployment.message_throughput, Some(1));
+    ///
+    /// let deployment = config.get_actor_deployment_config("default", "other");
+    /// assert_eq!(deployment.mailbox_size, Some(10));
+    /// assert_eq!(deployment.message_throughput, Some(5));
+    /// ```
+    pub fn get_actor_deployment_config(
+        &self,
+        pool_name: &str,
+        actor_name: &str,
+    ) -> ActorDeploymentConfig {
+        let mut patterns: Vec<&String> = self
+            .actors
+            .keys()
+            .filter(|pattern| ActorDeploymentConfig::matches(pattern, pool_name, actor_name))
+            .collect();
+        patterns.sort_by_key(|pattern| {
+            (
+                ActorDeploymentConfig::get_specificity(pattern),
+                pattern.to_string(),
+            )
+        });
+
+        let mut deployment = ActorDeploymentConfig::default();
+        for pattern in patterns {
+            deployment.merge(self.actors.get(pattern).unwrap());
+        }
+        return deployment;
+    }
+}
+
+impl FromStr for TyraConfig {
+    type Err = ConfigError;
+
+    /// Same as [new](#method.new), but loads the config from a toml string instead of `./config/tyra.toml`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tyra::prelude::TyraConfig;
+    /// use std::str::FromStr;
+    ///
+    /// let config = TyraConfig::from_str("[general]\nname = \"HelloWorld\"").unwrap();
+    /// assert_eq!(config.general.name, "HelloWorld");
+    /// assert_eq!(config.general.default_message_throughput, 15);
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let config = Self::get_default_builder().add_source(File::from_str(s, FileFormat::Toml));
+        return Self::build(config);
     }
 }
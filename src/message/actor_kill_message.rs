@@ -0,0 +1,15 @@
+use crate::message::actor_message::DefaultActorMessage;
+
+pub struct ActorKillMessage {}
+
+impl ActorKillMessage {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl DefaultActorMessage for ActorKillMessage {
+    fn is_control_message(&self) -> bool {
+        return true;
+    }
+}
@@ -3,7 +3,17 @@
 ///
 /// It is used by Messages defined in the system
 /// All messages that use this trait directly should also implement a dynamic `Handler<M>` that applies to any `Actor`
-pub trait BaseActorMessage: Send + Sync {}
+pub trait BaseActorMessage: Send + Sync {
+    /// returns the priority of the message, messages with a higher priority are handled first by actors with a priority mailbox
+    fn get_priority(&self) -> usize {
+        return 0;
+    }
+
+    /// control messages are sent through a separate lane of the mailbox that is always handled before any other message
+    fn is_control_message(&self) -> bool {
+        return false;
+    }
+}
 
 /// This trait is used by Messages defined by the system
 /// All messages that use this trait should also implement a dynamic `Handler<M>` that applies to any `Actor`
@@ -12,9 +22,30 @@ pub trait DefaultActorMessage: Send + Sync {
     fn get_id(&self) -> usize {
         return 0;
     }
+
+    /// returns the priority of the message, messages with a higher priority are handled first by actors with a priority mailbox
+    fn get_priority(&self) -> usize {
+        return 0;
+    }
+
+    /// control messages are sent through a separate lane of the mailbox that is always handled before any other message
+    fn is_control_message(&self) -> bool {
+        return false;
+    }
 }
 
-impl<A> BaseActorMessage for A where A: DefaultActorMessage {}
+impl<A> BaseActorMessage for A
+where
+    A: DefaultActorMessage,
+{
+    fn get_priority(&self) -> usize {
+        return DefaultActorMessage::get_priority(self);
+    }
+
+    fn is_control_message(&self) -> bool {
+        return DefaultActorMessage::is_control_message(self);
+    }
+}
 
 /// Core trait to define Messages
 ///
@@ -33,7 +64,22 @@ pub trait ActorMessage: Send + Sync {
     fn get_id(&self) -> usize {
         return 0;
     }
+
+    /// returns the priority of the message
+    ///
+    /// Only used by actors with a priority mailbox, see [ActorBuilder.set_priority_mailbox](../prelude/struct.ActorBuilder.html#method.set_priority_mailbox)
+    /// Messages with a higher priority are handled first, messages with the same priority are handled in the order they were sent
+    fn get_priority(&self) -> usize {
+        return 0;
+    }
 }
 
 /// this should be `BaseActorMessage` but it's currently not possible because of https://github.com/rust-lang/rust/issues/20400
-impl<A> DefaultActorMessage for A where A: ActorMessage {}
+impl<A> DefaultActorMessage for A
+where
+    A: ActorMessage,
+{
+    fn get_priority(&self) -> usize {
+        return ActorMessage::get_priority(self);
+    }
+}
@@ -0,0 +1,23 @@
+use crate::message::actor_message::ActorMessage;
+
+/// Core trait to define Messages that are answered with a typed response
+///
+/// Requests can be sent like any other [ActorMessage] and can additionally be sent through [ActorWrapper.ask](../prelude/struct.ActorWrapper.html#method.ask), if the Actor implements the corresponding [RequestHandler](../prelude/trait.RequestHandler.html)
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```rust
+/// use tyra::prelude::{ActorMessage, ActorRequest};
+///
+/// struct GetCount {}
+/// impl ActorMessage for GetCount {}
+/// impl ActorRequest for GetCount {
+///     type Response = usize;
+/// }
+/// ```
+pub trait ActorRequest: ActorMessage {
+    /// type of the response that is returned to the sender of the request
+    type Response: Send + 'static;
+}
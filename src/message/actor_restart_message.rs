@@ -0,0 +1,15 @@
+use crate::message::actor_message::DefaultActorMessage;
+
+pub struct ActorRestartMessage {}
+
+impl ActorRestartMessage {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl DefaultActorMessage for ActorRestartMessage {
+    fn is_control_message(&self) -> bool {
+        return true;
+    }
+}
@@ -8,4 +8,8 @@ impl ActorStopMessage {
     }
 }
 
-impl DefaultActorMessage for ActorStopMessage {}
+impl DefaultActorMessage for ActorStopMessage {
+    fn is_control_message(&self) -> bool {
+        return true;
+    }
+}
@@ -0,0 +1,23 @@
+use crate::actor::actor_address::ActorAddress;
+use crate::actor::termination_reason::TerminationReason;
+use crate::message::actor_message::DefaultActorMessage;
+
+/// Sent to all actors that [watch](../prelude/struct.ActorContext.html#method.watch) an actor, when that actor terminates
+///
+/// Handled through [Actor.on_actor_terminated](../prelude/trait.Actor.html#method.on_actor_terminated)
+pub struct ActorTerminated {
+    pub address: ActorAddress,
+    pub reason: TerminationReason,
+}
+
+impl ActorTerminated {
+    pub fn new(address: ActorAddress, reason: TerminationReason) -> Self {
+        Self { address, reason }
+    }
+}
+
+impl DefaultActorMessage for ActorTerminated {
+    fn is_control_message(&self) -> bool {
+        return true;
+    }
+}
@@ -0,0 +1,20 @@
+use crate::message::actor_message::BaseActorMessage;
+
+/// Wraps an [ActorMessage](../prelude/trait.ActorMessage.html) to be handled by the corresponding [AsyncHandler](../prelude/trait.AsyncHandler.html) of an Actor
+pub struct AsyncActorMessage<M>
+where
+    M: BaseActorMessage + 'static,
+{
+    pub msg: M,
+}
+
+impl<M> BaseActorMessage for AsyncActorMessage<M> where M: BaseActorMessage + 'static {}
+
+impl<M> AsyncActorMessage<M>
+where
+    M: BaseActorMessage + 'static,
+{
+    pub fn new(msg: M) -> Self {
+        Self { msg }
+    }
+}
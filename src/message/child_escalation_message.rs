@@ -0,0 +1,19 @@
+use crate::actor::actor_address::ActorAddress;
+use crate::message::actor_message::DefaultActorMessage;
+
+/// Sent to the parent of an actor that escalated a failure
+pub struct ChildEscalationMessage {
+    pub child: ActorAddress,
+}
+
+impl ChildEscalationMessage {
+    pub fn new(child: ActorAddress) -> Self {
+        Self { child }
+    }
+}
+
+impl DefaultActorMessage for ChildEscalationMessage {
+    fn is_control_message(&self) -> bool {
+        return true;
+    }
+}
@@ -0,0 +1,27 @@
+use crate::actor::actor_address::ActorAddress;
+use crate::message::actor_message::ActorMessage;
+use crate::message::dead_letter_reason::DeadLetterReason;
+
+/// Describes a message that could not be delivered or was dropped
+///
+/// Published to all actors that subscribed through [ActorSystem.subscribe_dead_letters](../prelude/struct.ActorSystem.html#method.subscribe_dead_letters)
+#[derive(Clone, Debug)]
+pub struct DeadLetter {
+    /// address of the actor the message was sent to
+    pub address: ActorAddress,
+    /// type name of the message
+    pub message_type: String,
+    pub reason: DeadLetterReason,
+}
+
+impl DeadLetter {
+    pub fn new(address: ActorAddress, message_type: &str, reason: DeadLetterReason) -> Self {
+        Self {
+            address,
+            message_type: String::from(message_type),
+            reason,
+        }
+    }
+}
+
+impl ActorMessage for DeadLetter {}
@@ -0,0 +1,14 @@
+#[derive(PartialEq, Clone, Copy, Debug)]
+/// Information provided through [DeadLetter](../prelude/struct.DeadLetter.html) to distinguish why a message could not be delivered
+pub enum DeadLetterReason {
+    /// the message was sent to an actor that has already been stopped
+    ActorStopped,
+    /// the message was still in the mailbox when the actor was stopped immediately
+    ActorKilled,
+    /// the message was sent to an address that does not belong to any actor
+    UnknownAddress,
+    /// the message was sent to a router without any routees
+    NoRoutees,
+    /// the message was sent to a full mailbox that uses [MailboxOverflowStrategy::DeadLetter](../prelude/enum.MailboxOverflowStrategy.html#variant.DeadLetter)
+    MailboxFull,
+}
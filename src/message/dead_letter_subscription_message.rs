@@ -0,0 +1,22 @@
+use crate::actor::actor_wrapper::ActorWrapper;
+use crate::message::actor_message::BaseActorMessage;
+use crate::prelude::Actor;
+
+/// Adds a subscriber to the internal dead letter actor
+pub struct DeadLetterSubscriptionMessage<A>
+where
+    A: Actor,
+{
+    pub subscriber: ActorWrapper<A>,
+}
+
+impl<A> DeadLetterSubscriptionMessage<A>
+where
+    A: Actor,
+{
+    pub fn new(subscriber: ActorWrapper<A>) -> Self {
+        Self { subscriber }
+    }
+}
+
+impl<A> BaseActorMessage for DeadLetterSubscriptionMessage<A> where A: Actor {}
@@ -2,6 +2,7 @@ use crate::actor::context::ActorContext;
 use crate::actor::handler::Handler;
 use crate::message::actor_message::BaseActorMessage;
 use crate::prelude::{Actor, ActorResult};
+use std::cmp::Ordering;
 use std::error::Error;
 
 pub trait MessageEnvelopeTrait<A>: Send + Sync
@@ -13,6 +14,12 @@ where
         actor: &mut A,
         context: &ActorContext<A>,
     ) -> Result<ActorResult, Box<dyn Error>>;
+
+    /// returns the type name of the wrapped message
+    fn get_type_name(&self) -> &'static str;
+
+    /// returns the priority of the wrapped message
+    fn get_priority(&self) -> usize;
 }
 
 pub struct MessageEnvelope<A>(Box<dyn MessageEnvelopeTrait<A> + Send + Sync>);
@@ -23,7 +30,11 @@ impl<A> MessageEnvelope<A> {
         A: Handler<M> + Actor,
         M: BaseActorMessage + Send + Sync + 'static,
     {
-        MessageEnvelope(Box::new(SyncMessageEnvelope { msg: Some(msg) }))
+        let priority = msg.get_priority();
+        MessageEnvelope(Box::new(SyncMessageEnvelope {
+            msg: Some(msg),
+            priority,
+        }))
     }
 }
 
@@ -38,6 +49,14 @@ where
     ) -> Result<ActorResult, Box<dyn Error>> {
         return self.0.handle(act, context);
     }
+
+    fn get_type_name(&self) -> &'static str {
+        return self.0.get_type_name();
+    }
+
+    fn get_priority(&self) -> usize {
+        return self.0.get_priority();
+    }
 }
 
 pub struct SyncMessageEnvelope<M>
@@ -45,6 +64,7 @@ where
     M: BaseActorMessage + Send + Sync,
 {
     msg: Option<M>,
+    priority: usize,
 }
 
 impl<A, M> MessageEnvelopeTrait<A> for SyncMessageEnvelope<M>
@@ -62,4 +82,56 @@ where
         }
         return Ok(ActorResult::Ok);
     }
+
+    fn get_type_name(&self) -> &'static str {
+        return std::any::type_name::<M>();
+    }
+
+    fn get_priority(&self) -> usize {
+        return self.priority;
+    }
+}
+
+/// Orders messages by their priority, messages with the same priority are ordered by their sequence number
+pub struct PrioritizedMessageEnvelope<A> {
+    pub envelope: MessageEnvelope<A>,
+    priority: usize,
+    sequence: usize,
+}
+
+impl<A> PrioritizedMessageEnvelope<A>
+where
+    A: Actor,
+{
+    pub fn new(envelope: MessageEnvelope<A>, sequence: usize) -> Self {
+        Self {
+            priority: envelope.get_priority(),
+            envelope,
+            sequence,
+        }
+    }
+}
+
+impl<A> PartialEq for PrioritizedMessageEnvelope<A> {
+    fn eq(&self, other: &Self) -> bool {
+        return self.priority == other.priority && self.sequence == other.sequence;
+    }
+}
+
+impl<A> Eq for PrioritizedMessageEnvelope<A> {}
+
+impl<A> PartialOrd for PrioritizedMessageEnvelope<A> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        return Some(self.cmp(other));
+    }
+}
+
+impl<A> Ord for PrioritizedMessageEnvelope<A> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // `BinaryHeap` is a max-heap, therefore older messages need to be greater
+        return self
+            .priority
+            .cmp(&other.priority)
+            .then_with(|| other.sequence.cmp(&self.sequence));
+    }
 }
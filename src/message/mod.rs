@@ -1,15 +1,29 @@
 pub mod actor_init_message;
+pub mod actor_kill_message;
 pub mod actor_message;
+pub mod actor_request;
+pub mod actor_restart_message;
 pub mod actor_stop_message;
+pub mod actor_terminated;
+pub mod async_actor_message;
 pub mod bulk_actor_message;
-pub mod delayed_message;
+pub mod child_escalation_message;
+pub mod dead_letter;
+pub mod dead_letter_reason;
+pub mod dead_letter_subscription_message;
 pub mod envelope;
+pub mod request_message;
 pub mod serialized_message;
 pub mod sleep_message;
 pub mod system_stop_message;
 pub mod prelude {
     pub use crate::message::actor_init_message::ActorInitMessage;
     pub use crate::message::actor_message::ActorMessage;
+    pub use crate::message::actor_request::ActorRequest;
+    pub use crate::message::actor_terminated::ActorTerminated;
+    pub use crate::message::async_actor_message::AsyncActorMessage;
     pub use crate::message::bulk_actor_message::BulkActorMessage;
+    pub use crate::message::dead_letter::DeadLetter;
+    pub use crate::message::dead_letter_reason::DeadLetterReason;
     pub use crate::message::serialized_message::SerializedMessage;
 }
@@ -0,0 +1,22 @@
+use crate::message::actor_message::BaseActorMessage;
+use crate::message::actor_request::ActorRequest;
+
+/// Wraps an [ActorRequest](../prelude/trait.ActorRequest.html) together with the channel that is used to return the response
+pub struct RequestMessage<M>
+where
+    M: ActorRequest + 'static,
+{
+    pub msg: M,
+    pub reply_to: flume::Sender<M::Response>,
+}
+
+impl<M> BaseActorMessage for RequestMessage<M> where M: ActorRequest + 'static {}
+
+impl<M> RequestMessage<M>
+where
+    M: ActorRequest + 'static,
+{
+    pub fn new(msg: M, reply_to: flume::Sender<M::Response>) -> Self {
+        Self { msg, reply_to }
+    }
+}
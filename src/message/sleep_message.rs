@@ -6,7 +6,11 @@ pub struct SleepMessage {
     pub duration: Duration,
 }
 
-impl DefaultActorMessage for SleepMessage {}
+impl DefaultActorMessage for SleepMessage {
+    fn is_control_message(&self) -> bool {
+        return true;
+    }
+}
 
 impl SleepMessage {
     pub fn new(duration: Duration) -> Self {
@@ -8,4 +8,8 @@ impl SystemStopMessage {
     }
 }
 
-impl DefaultActorMessage for SystemStopMessage {}
+impl DefaultActorMessage for SystemStopMessage {
+    fn is_control_message(&self) -> bool {
+        return true;
+    }
+}
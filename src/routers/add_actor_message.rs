@@ -1,22 +1,21 @@
-use crate::actor::actor_wrapper::ActorWrapper;
 use crate::message::actor_message::BaseActorMessage;
-use crate::prelude::Actor;
+use crate::routers::routee::Routee;
 
-/// Adds an Actor to the Router
-pub struct AddActorMessage<A>
+/// Adds an Actor to the Router, either as [ActorWrapper](../prelude/struct.ActorWrapper.html) or as [Recipient](../prelude/struct.Recipient.html)
+pub struct AddActorMessage<R>
 where
-    A: Actor,
+    R: Routee,
 {
-    pub actor: ActorWrapper<A>,
+    pub actor: R,
 }
 
-impl<A> AddActorMessage<A>
+impl<R> AddActorMessage<R>
 where
-    A: Actor,
+    R: Routee,
 {
-    pub fn new(actor: ActorWrapper<A>) -> Self {
+    pub fn new(actor: R) -> Self {
         Self { actor }
     }
 }
 
-impl<A> BaseActorMessage for AddActorMessage<A> where A: Actor {}
+impl<R> BaseActorMessage for AddActorMessage<R> where R: Routee {}
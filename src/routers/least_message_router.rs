@@ -1,20 +1,24 @@
+use crate::actor::actor_address::ActorAddress;
 use crate::actor::actor_factory::ActorFactory;
-use crate::actor::actor_wrapper::ActorWrapper;
 use crate::actor::context::ActorContext;
 use crate::actor::handler::Handler;
-use crate::prelude::{Actor, ActorMessage, ActorResult};
+use crate::message::dead_letter::DeadLetter;
+use crate::message::dead_letter_reason::DeadLetterReason;
+use crate::prelude::{Actor, ActorMessage, ActorResult, ActorTerminated};
 use crate::routers::add_actor_message::AddActorMessage;
 use crate::routers::remove_actor_message::RemoveActorMessage;
+use crate::routers::routee::{Routee, RouteeHandler};
 use log::error;
+use std::any::type_name;
 use std::error::Error;
 
-pub struct LeastMessageRouter<A>
+pub struct LeastMessageRouter<R>
 where
-    A: Actor,
+    R: Routee,
 {
     next_route_index: usize,
     min_mailbox_size: usize,
-    route_to: Vec<ActorWrapper<A>>,
+    route_to: Vec<R>,
     can_route: bool,
 }
 
@@ -84,21 +88,21 @@ impl LeastMessageRouterFactory {
     }
 }
 
-impl<A> ActorFactory<LeastMessageRouter<A>> for LeastMessageRouterFactory
+impl<R> ActorFactory<LeastMessageRouter<R>> for LeastMessageRouterFactory
 where
-    A: Actor + 'static,
+    R: Routee,
 {
     fn new_actor(
         &mut self,
-        _context: ActorContext<LeastMessageRouter<A>>,
-    ) -> Result<LeastMessageRouter<A>, Box<dyn Error>> {
+        _context: ActorContext<LeastMessageRouter<R>>,
+    ) -> Result<LeastMessageRouter<R>, Box<dyn Error>> {
         return Ok(LeastMessageRouter::new(self.min_mailbox_size));
     }
 }
 
-impl<A> LeastMessageRouter<A>
+impl<R> LeastMessageRouter<R>
 where
-    A: Actor,
+    R: Routee,
 {
     pub fn new(min_mailbox_size: usize) -> Self {
         Self {
@@ -108,59 +112,85 @@ where
             can_route: false,
         }
     }
+
+    fn remove_routee(&mut self, address: &ActorAddress) {
+        if let Some(pos) = self
+            .route_to
+            .iter()
+            .position(|x| x.get_address() == address)
+        {
+            self.route_to.remove(pos);
+        }
+        if self.next_route_index >= self.route_to.len() {
+            self.next_route_index = 0;
+        }
+        if self.route_to.len() == 0 {
+            self.can_route = false
+        }
+    }
 }
 
-impl<A> Actor for LeastMessageRouter<A> where A: Actor {}
+impl<R> Actor for LeastMessageRouter<R>
+where
+    R: Routee,
+{
+    fn on_actor_terminated(
+        &mut self,
+        _context: &ActorContext<Self>,
+        msg: ActorTerminated,
+    ) -> Result<ActorResult, Box<dyn Error>> {
+        self.remove_routee(&msg.address);
+        return Ok(ActorResult::Ok);
+    }
+}
 
-impl<A> Handler<AddActorMessage<A>> for LeastMessageRouter<A>
+impl<R> Handler<AddActorMessage<R>> for LeastMessageRouter<R>
 where
-    A: Actor,
+    R: Routee,
 {
     fn handle(
         &mut self,
-        msg: AddActorMessage<A>,
-        _context: &ActorContext<Self>,
+        msg: AddActorMessage<R>,
+        context: &ActorContext<Self>,
     ) -> Result<ActorResult, Box<dyn Error>> {
+        context.watch_address(msg.actor.get_address());
         self.route_to.push(msg.actor);
         self.can_route = true;
         return Ok(ActorResult::Ok);
     }
 }
 
-impl<A> Handler<RemoveActorMessage<A>> for LeastMessageRouter<A>
+impl<R> Handler<RemoveActorMessage<R>> for LeastMessageRouter<R>
 where
-    A: Actor,
+    R: Routee,
 {
     fn handle(
         &mut self,
-        msg: RemoveActorMessage<A>,
-        _context: &ActorContext<Self>,
+        msg: RemoveActorMessage<R>,
+        context: &ActorContext<Self>,
     ) -> Result<ActorResult, Box<dyn Error>> {
-        if let Some(pos) = self
-            .route_to
-            .iter()
-            .position(|x| x.get_address() == msg.actor.get_address())
-        {
-            self.route_to.remove(pos);
-        }
-        if self.route_to.len() == 0 {
-            self.can_route = false
-        }
+        context.unwatch_address(msg.actor.get_address());
+        self.remove_routee(msg.actor.get_address());
         return Ok(ActorResult::Ok);
     }
 }
 
-impl<A, M> Handler<M> for LeastMessageRouter<A>
+impl<R, M> Handler<M> for LeastMessageRouter<R>
 where
-    A: Actor + Handler<M> + 'static,
+    R: RouteeHandler<M>,
     M: ActorMessage + 'static,
 {
     fn handle(
         &mut self,
         msg: M,
-        _context: &ActorContext<Self>,
+        context: &ActorContext<Self>,
     ) -> Result<ActorResult, Box<dyn Error>> {
         if !self.can_route {
+            context.system.send_dead_letter(DeadLetter::new(
+                context.actor_ref.get_address().clone(),
+                type_name::<M>(),
+                DeadLetterReason::NoRoutees,
+            ));
             return Ok(ActorResult::Ok);
         }
 
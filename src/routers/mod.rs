@@ -3,6 +3,7 @@ mod bulk_router_message;
 mod least_message_router;
 mod remove_actor_message;
 mod round_robin_router;
+mod routee;
 mod sharded_router;
 
 pub mod prelude {
@@ -13,6 +14,8 @@ pub mod prelude {
     pub use crate::routers::remove_actor_message::RemoveActorMessage;
     pub use crate::routers::round_robin_router::RoundRobinRouter;
     pub use crate::routers::round_robin_router::RoundRobinRouterFactory;
+    pub use crate::routers::routee::Routee;
+    pub use crate::routers::routee::RouteeHandler;
     pub use crate::routers::sharded_router::ShardedRouter;
     pub use crate::routers::sharded_router::ShardedRouterFactory;
 }
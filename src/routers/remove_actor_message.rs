@@ -1,22 +1,21 @@
-use crate::actor::actor_wrapper::ActorWrapper;
 use crate::message::actor_message::BaseActorMessage;
-use crate::prelude::Actor;
+use crate::routers::routee::Routee;
 
-/// Removes an Actor from the Router
-pub struct RemoveActorMessage<A>
+/// Removes an Actor from the Router, either as [ActorWrapper](../prelude/struct.ActorWrapper.html) or as [Recipient](../prelude/struct.Recipient.html)
+pub struct RemoveActorMessage<R>
 where
-    A: Actor,
+    R: Routee,
 {
-    pub actor: ActorWrapper<A>,
+    pub actor: R,
 }
 
-impl<A> RemoveActorMessage<A>
+impl<R> RemoveActorMessage<R>
 where
-    A: Actor,
+    R: Routee,
 {
-    pub fn new(actor: ActorWrapper<A>) -> Self {
+    pub fn new(actor: R) -> Self {
         Self { actor }
     }
 }
 
-impl<A> BaseActorMessage for RemoveActorMessage<A> where A: Actor {}
+impl<R> BaseActorMessage for RemoveActorMessage<R> where R: Routee {}
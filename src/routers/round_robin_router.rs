@@ -1,21 +1,25 @@
+use crate::actor::actor_address::ActorAddress;
 use crate::actor::actor_factory::ActorFactory;
-use crate::actor::actor_wrapper::ActorWrapper;
 use crate::actor::context::ActorContext;
 use crate::actor::handler::Handler;
 use crate::message::actor_message::{ActorMessage, BaseActorMessage};
-use crate::prelude::{Actor, ActorResult, BulkActorMessage};
+use crate::message::dead_letter::DeadLetter;
+use crate::message::dead_letter_reason::DeadLetterReason;
+use crate::prelude::{Actor, ActorResult, ActorTerminated, BulkActorMessage};
 use crate::routers::add_actor_message::AddActorMessage;
 use crate::routers::bulk_router_message::BulkRouterMessage;
 use crate::routers::remove_actor_message::RemoveActorMessage;
+use crate::routers::routee::{Routee, RouteeHandler};
 use log::error;
+use std::any::type_name;
 use std::error::Error;
 
-pub struct RoundRobinRouter<A>
+pub struct RoundRobinRouter<R>
 where
-    A: Actor,
+    R: Routee,
 {
     route_index: usize,
-    route_to: Vec<ActorWrapper<A>>,
+    route_to: Vec<R>,
     can_route: bool,
 }
 
@@ -76,6 +80,68 @@ where
 /// router.send(AddActorMessage::new(actor.clone())).unwrap();
 /// router.send(FooBar{}).unwrap();
 /// ```
+///
+/// Routing to actors of different types through [Recipients](../prelude/struct.Recipient.html):
+///
+/// ```rust
+/// use std::error::Error;
+/// use tyra::prelude::*;
+/// use tyra::router::{RoundRobinRouterFactory, AddActorMessage};
+///
+/// // define message
+/// struct FooBar {}
+/// impl ActorMessage for FooBar {}
+///
+/// // define two different actors that are able to handle the message
+/// struct Hello {}
+/// impl Actor for Hello {}
+/// impl Handler<FooBar> for Hello {
+///     fn handle(&mut self, _msg: FooBar, _context: &ActorContext<Self>) -> Result<ActorResult, Box<dyn Error>> {
+///         Ok(ActorResult::Ok)
+///     }
+/// }
+///
+/// struct World {}
+/// impl Actor for World {}
+/// impl Handler<FooBar> for World {
+///     fn handle(&mut self, _msg: FooBar, _context: &ActorContext<Self>) -> Result<ActorResult, Box<dyn Error>> {
+///         Ok(ActorResult::Ok)
+///     }
+/// }
+///
+/// // setup required Factories
+/// struct HelloFactory {}
+/// impl ActorFactory<Hello> for HelloFactory {
+///     fn new_actor(&mut self, _context: ActorContext<Hello>) -> Result<Hello, Box<dyn Error>> {
+///         Ok(Hello {})
+///     }
+/// }
+///
+/// struct WorldFactory {}
+/// impl ActorFactory<World> for WorldFactory {
+///     fn new_actor(&mut self, _context: ActorContext<World>) -> Result<World, Box<dyn Error>> {
+///         Ok(World {})
+///     }
+/// }
+///
+/// // create a new actor system with the default config
+/// let actor_config = TyraConfig::new().unwrap();
+/// let actor_system = ActorSystem::new(actor_config);
+///
+/// // create the actors
+/// let hello = actor_system.builder().spawn("hello", HelloFactory {}).unwrap();
+/// let world = actor_system.builder().spawn("world", WorldFactory {}).unwrap();
+///
+/// // create the router, fill it, and route messages
+/// let router = actor_system
+///     .builder()
+///     .spawn("router-foo-bar", RoundRobinRouterFactory::new())
+///     .unwrap();
+/// router.send(AddActorMessage::new(hello.get_recipient::<FooBar>())).unwrap();
+/// router.send(AddActorMessage::new(world.get_recipient::<FooBar>())).unwrap();
+/// router.send(FooBar{}).unwrap();
+/// router.send(FooBar{}).unwrap();
+/// ```
 pub struct RoundRobinRouterFactory {}
 
 impl RoundRobinRouterFactory {
@@ -84,21 +150,21 @@ impl RoundRobinRouterFactory {
     }
 }
 
-impl<A> ActorFactory<RoundRobinRouter<A>> for RoundRobinRouterFactory
+impl<R> ActorFactory<RoundRobinRouter<R>> for RoundRobinRouterFactory
 where
-    A: Actor + 'static,
+    R: Routee,
 {
     fn new_actor(
         &mut self,
-        _context: ActorContext<RoundRobinRouter<A>>,
-    ) -> Result<RoundRobinRouter<A>, Box<dyn Error>> {
+        _context: ActorContext<RoundRobinRouter<R>>,
+    ) -> Result<RoundRobinRouter<R>, Box<dyn Error>> {
         return Ok(RoundRobinRouter::new());
     }
 }
 
-impl<A> RoundRobinRouter<A>
+impl<R> RoundRobinRouter<R>
 where
-    A: Actor,
+    R: Routee,
 {
     pub fn new() -> Self {
         Self {
@@ -107,59 +173,82 @@ where
             can_route: false,
         }
     }
+
+    fn remove_routee(&mut self, address: &ActorAddress) {
+        if let Some(pos) = self
+            .route_to
+            .iter()
+            .position(|x| x.get_address() == address)
+        {
+            self.route_to.remove(pos);
+        }
+        if self.route_to.len() == 0 {
+            self.can_route = false
+        }
+    }
 }
 
-impl<A> Actor for RoundRobinRouter<A> where A: Actor {}
+impl<R> Actor for RoundRobinRouter<R>
+where
+    R: Routee,
+{
+    fn on_actor_terminated(
+        &mut self,
+        _context: &ActorContext<Self>,
+        msg: ActorTerminated,
+    ) -> Result<ActorResult, Box<dyn Error>> {
+        self.remove_routee(&msg.address);
+        return Ok(ActorResult::Ok);
+    }
+}
 
-impl<A> Handler<AddActorMessage<A>> for RoundRobinRouter<A>
+impl<R> Handler<AddActorMessage<R>> for RoundRobinRouter<R>
 where
-    A: Actor,
+    R: Routee,
 {
     fn handle(
         &mut self,
-        msg: AddActorMessage<A>,
-        _context: &ActorContext<Self>,
+        msg: AddActorMessage<R>,
+        context: &ActorContext<Self>,
     ) -> Result<ActorResult, Box<dyn Error>> {
+        context.watch_address(msg.actor.get_address());
         self.route_to.push(msg.actor);
         self.can_route = true;
         return Ok(ActorResult::Ok);
     }
 }
 
-impl<A> Handler<RemoveActorMessage<A>> for RoundRobinRouter<A>
+impl<R> Handler<RemoveActorMessage<R>> for RoundRobinRouter<R>
 where
-    A: Actor,
+    R: Routee,
 {
     fn handle(
         &mut self,
-        msg: RemoveActorMessage<A>,
-        _context: &ActorContext<Self>,
+        msg: RemoveActorMessage<R>,
+        context: &ActorContext<Self>,
     ) -> Result<ActorResult, Box<dyn Error>> {
-        if let Some(pos) = self
-            .route_to
-            .iter()
-            .position(|x| x.get_address() == msg.actor.get_address())
-        {
-            self.route_to.remove(pos);
-        }
-        if self.route_to.len() == 0 {
-            self.can_route = false
-        }
+        context.unwatch_address(msg.actor.get_address());
+        self.remove_routee(msg.actor.get_address());
         return Ok(ActorResult::Ok);
     }
 }
 
-impl<A, M> Handler<M> for RoundRobinRouter<A>
+impl<R, M> Handler<M> for RoundRobinRouter<R>
 where
-    A: Actor + Handler<M> + 'static,
+    R: RouteeHandler<M>,
     M: ActorMessage + 'static,
 {
     fn handle(
         &mut self,
         msg: M,
-        _context: &ActorContext<Self>,
+        context: &ActorContext<Self>,
     ) -> Result<ActorResult, Box<dyn Error>> {
         if !self.can_route {
+            context.system.send_dead_letter(DeadLetter::new(
+                context.actor_ref.get_address().clone(),
+                type_name::<M>(),
+                DeadLetterReason::NoRoutees,
+            ));
             return Ok(ActorResult::Ok);
         }
 
@@ -180,17 +269,22 @@ where
     }
 }
 
-impl<A, M> Handler<BulkRouterMessage<M>> for RoundRobinRouter<A>
+impl<R, M> Handler<BulkRouterMessage<M>> for RoundRobinRouter<R>
 where
-    A: Actor + Handler<BulkActorMessage<M>> + 'static,
+    R: RouteeHandler<BulkActorMessage<M>>,
     M: BaseActorMessage + 'static,
 {
     fn handle(
         &mut self,
         mut msg: BulkRouterMessage<M>,
-        _context: &ActorContext<Self>,
+        context: &ActorContext<Self>,
     ) -> Result<ActorResult, Box<dyn Error>> {
         if !self.can_route {
+            context.system.send_dead_letter(DeadLetter::new(
+                context.actor_ref.get_address().clone(),
+                type_name::<BulkRouterMessage<M>>(),
+                DeadLetterReason::NoRoutees,
+            ));
             return Ok(ActorResult::Ok);
         }
 
@@ -0,0 +1,69 @@
+use crate::actor::actor_address::ActorAddress;
+use crate::actor::actor_send_error::ActorSendError;
+use crate::actor::actor_wrapper::ActorWrapper;
+use crate::actor::handler::Handler;
+use crate::actor::recipient::Recipient;
+use crate::message::actor_message::BaseActorMessage;
+use crate::prelude::Actor;
+use std::panic::UnwindSafe;
+
+/// Target of a router, implemented by [ActorWrapper](../prelude/struct.ActorWrapper.html) and [Recipient](../prelude/struct.Recipient.html)
+///
+/// Routers that are filled with `Recipient<M>` are able to route `M` to actors of different types
+pub trait Routee: Clone + Send + Sync + UnwindSafe + 'static {
+    fn get_address(&self) -> &ActorAddress;
+    fn get_mailbox_size(&self) -> usize;
+}
+
+/// [Routee](trait.Routee.html) that is able to handle messages of type `M`
+pub trait RouteeHandler<M>: Routee
+where
+    M: BaseActorMessage + 'static,
+{
+    fn send(&self, msg: M) -> Result<(), ActorSendError>;
+}
+
+impl<A> Routee for ActorWrapper<A>
+where
+    A: Actor + 'static,
+{
+    fn get_address(&self) -> &ActorAddress {
+        return ActorWrapper::get_address(self);
+    }
+
+    fn get_mailbox_size(&self) -> usize {
+        return ActorWrapper::get_mailbox_size(self);
+    }
+}
+
+impl<A, M> RouteeHandler<M> for ActorWrapper<A>
+where
+    A: Handler<M> + 'static,
+    M: BaseActorMessage + 'static,
+{
+    fn send(&self, msg: M) -> Result<(), ActorSendError> {
+        return ActorWrapper::send(self, msg);
+    }
+}
+
+impl<M> Routee for Recipient<M>
+where
+    M: BaseActorMessage + 'static,
+{
+    fn get_address(&self) -> &ActorAddress {
+        return Recipient::get_address(self);
+    }
+
+    fn get_mailbox_size(&self) -> usize {
+        return Recipient::get_mailbox_size(self);
+    }
+}
+
+impl<M> RouteeHandler<M> for Recipient<M>
+where
+    M: BaseActorMessage + 'static,
+{
+    fn send(&self, msg: M) -> Result<(), ActorSendError> {
+        return Recipient::send(self, msg);
+    }
+}
@@ -1,23 +1,27 @@
+use crate::actor::actor_address::ActorAddress;
 use crate::actor::actor_factory::ActorFactory;
-use crate::actor::actor_wrapper::ActorWrapper;
 use crate::actor::context::ActorContext;
 use crate::actor::handler::Handler;
 use crate::message::actor_message::BaseActorMessage;
-use crate::prelude::{Actor, ActorMessage, ActorResult, BulkActorMessage};
+use crate::message::dead_letter::DeadLetter;
+use crate::message::dead_letter_reason::DeadLetterReason;
+use crate::prelude::{Actor, ActorMessage, ActorResult, ActorTerminated, BulkActorMessage};
 use crate::routers::add_actor_message::AddActorMessage;
 use crate::routers::bulk_router_message::BulkRouterMessage;
 use crate::routers::remove_actor_message::RemoveActorMessage;
+use crate::routers::routee::{Routee, RouteeHandler};
 use log::error;
+use std::any::type_name;
 use std::collections::HashMap;
 use std::error::Error;
 
-pub struct ShardedRouter<A>
+pub struct ShardedRouter<R>
 where
-    A: Actor,
+    R: Routee,
 {
     num_shards: usize,
-    route_to: Vec<ActorWrapper<A>>,
-    sharding: HashMap<usize, ActorWrapper<A>>,
+    route_to: Vec<R>,
+    sharding: HashMap<usize, R>,
     can_route: bool,
 }
 
@@ -86,21 +90,21 @@ impl ShardedRouterFactory {
     }
 }
 
-impl<A> ActorFactory<ShardedRouter<A>> for ShardedRouterFactory
+impl<R> ActorFactory<ShardedRouter<R>> for ShardedRouterFactory
 where
-    A: Actor + 'static,
+    R: Routee,
 {
     fn new_actor(
         &mut self,
-        _context: ActorContext<ShardedRouter<A>>,
-    ) -> Result<ShardedRouter<A>, Box<dyn Error>> {
+        _context: ActorContext<ShardedRouter<R>>,
+    ) -> Result<ShardedRouter<R>, Box<dyn Error>> {
         return Ok(ShardedRouter::new());
     }
 }
 
-impl<A> ShardedRouter<A>
+impl<R> ShardedRouter<R>
 where
-    A: Actor,
+    R: Routee,
 {
     pub fn new() -> Self {
         Self {
@@ -120,19 +124,46 @@ where
             self.sharding.insert(i, routee);
         }
     }
+
+    fn remove_routee(&mut self, address: &ActorAddress) {
+        if let Some(pos) = self
+            .route_to
+            .iter()
+            .position(|x| x.get_address() == address)
+        {
+            self.route_to.remove(pos);
+            self.recalculate_shards();
+        }
+        if self.route_to.len() == 0 {
+            self.can_route = false
+        }
+    }
 }
 
-impl<A> Actor for ShardedRouter<A> where A: Actor {}
+impl<R> Actor for ShardedRouter<R>
+where
+    R: Routee,
+{
+    fn on_actor_terminated(
+        &mut self,
+        _context: &ActorContext<Self>,
+        msg: ActorTerminated,
+    ) -> Result<ActorResult, Box<dyn Error>> {
+        self.remove_routee(&msg.address);
+        return Ok(ActorResult::Ok);
+    }
+}
 
-impl<A> Handler<AddActorMessage<A>> for ShardedRouter<A>
+impl<R> Handler<AddActorMessage<R>> for ShardedRouter<R>
 where
-    A: Actor,
+    R: Routee,
 {
     fn handle(
         &mut self,
-        msg: AddActorMessage<A>,
-        _context: &ActorContext<Self>,
+        msg: AddActorMessage<R>,
+        context: &ActorContext<Self>,
     ) -> Result<ActorResult, Box<dyn Error>> {
+        context.watch_address(msg.actor.get_address());
         self.route_to.push(msg.actor);
         self.can_route = true;
         self.recalculate_shards();
@@ -140,41 +171,37 @@ where
     }
 }
 
-impl<A> Handler<RemoveActorMessage<A>> for ShardedRouter<A>
+impl<R> Handler<RemoveActorMessage<R>> for ShardedRouter<R>
 where
-    A: Actor,
+    R: Routee,
 {
     fn handle(
         &mut self,
-        msg: RemoveActorMessage<A>,
-        _context: &ActorContext<Self>,
+        msg: RemoveActorMessage<R>,
+        context: &ActorContext<Self>,
     ) -> Result<ActorResult, Box<dyn Error>> {
-        if let Some(pos) = self
-            .route_to
-            .iter()
-            .position(|x| x.get_address() == msg.actor.get_address())
-        {
-            self.route_to.remove(pos);
-            self.recalculate_shards();
-        }
-        if self.route_to.len() == 0 {
-            self.can_route = false
-        }
+        context.unwatch_address(msg.actor.get_address());
+        self.remove_routee(msg.actor.get_address());
         return Ok(ActorResult::Ok);
     }
 }
 
-impl<A, M> Handler<M> for ShardedRouter<A>
+impl<R, M> Handler<M> for ShardedRouter<R>
 where
-    A: Actor + Handler<M> + 'static,
+    R: RouteeHandler<M>,
     M: ActorMessage + 'static,
 {
     fn handle(
         &mut self,
         msg: M,
-        _context: &ActorContext<Self>,
+        context: &ActorContext<Self>,
     ) -> Result<ActorResult, Box<dyn Error>> {
         if !self.can_route {
+            context.system.send_dead_letter(DeadLetter::new(
+                context.actor_ref.get_address().clone(),
+                type_name::<M>(),
+                DeadLetterReason::NoRoutees,
+            ));
             return Ok(ActorResult::Ok);
         }
 
@@ -191,17 +218,22 @@ where
     }
 }
 
-impl<A, M> Handler<BulkRouterMessage<M>> for ShardedRouter<A>
+impl<R, M> Handler<BulkRouterMessage<M>> for ShardedRouter<R>
 where
-    A: Actor + Handler<BulkActorMessage<M>> + 'static,
+    R: RouteeHandler<BulkActorMessage<M>>,
     M: BaseActorMessage + 'static,
 {
     fn handle(
         &mut self,
         mut msg: BulkRouterMessage<M>,
-        _context: &ActorContext<Self>,
+        context: &ActorContext<Self>,
     ) -> Result<ActorResult, Box<dyn Error>> {
         if !self.can_route {
+            context.system.send_dead_letter(DeadLetter::new(
+                context.actor_ref.get_address().clone(),
+                type_name::<BulkRouterMessage<M>>(),
+                DeadLetterReason::NoRoutees,
+            ));
             return Ok(ActorResult::Ok);
         }
 
@@ -1,3 +1,5 @@
+use crate::actor::actor_address::ActorAddress;
+use crate::config::config_validation_error::ConfigValidationError;
 use thiserror::Error;
 
 #[derive(Error, Debug, PartialEq)]
@@ -14,7 +16,31 @@ pub enum ActorError {
     #[error("Actor could not be started, because thread-pool is full")]
     ThreadPoolHasTooManyActorsError,
 
+    /// Triggered by [ActorBuilder.spawn](../prelude/struct.ActorBuilder.html#method.spawn) if the name of the actor is not [valid](../prelude/struct.ActorAddress.html#method.is_valid_name)
+    #[error("Actor name {} contains illegal characters", .name)]
+    InvalidActorNameError { name: String },
+
     /// Triggered by [ActorBuilder.spawn](../prelude/struct.ActorBuilder.html#method.spawn) if the actor can't be spawned, because the thread-pool does not exist
     #[error("Actor could not be started, because thread-pool does not exist")]
     ThreadPoolDoesNotExistError,
+
+    /// Triggered by [ActorSystem.add_pool](../prelude/struct.ActorSystem.html#method.add_pool) if the name of the thread-pool is not [valid](../prelude/struct.ActorAddress.html#method.is_valid_name)
+    #[error("Thread-pool name {} contains illegal characters", .name)]
+    InvalidThreadPoolNameError { name: String },
+
+    /// Triggered by [ActorSystem.get_actor](../prelude/struct.ActorSystem.html#method.get_actor) if there's no actor with the given address
+    #[error("Actor does not exist")]
+    ActorDoesNotExistError,
+
+    /// Triggered by [ActorSystem.update_pool_config](../prelude/struct.ActorSystem.html#method.update_pool_config) if the new [ThreadPoolConfig](../prelude/struct.ThreadPoolConfig.html) is not [valid](../prelude/struct.ThreadPoolConfig.html#method.validate)
+    #[error("Thread-pool config is invalid: {:?}", .errors)]
+    InvalidThreadPoolConfigError { errors: Vec<ConfigValidationError> },
+
+    /// Triggered by [ActorSystem.remove_pool](../prelude/struct.ActorSystem.html#method.remove_pool) if the `default` or the internal `tyra` thread-pool should be removed, both are required by [TyraConfig.validate](../prelude/struct.TyraConfig.html#method.validate)
+    #[error("Thread-pool is required by the system and can't be removed")]
+    RequiredThreadPoolError,
+
+    /// Passed to [on_error](../prelude/trait.Actor.html#method.on_error) of the parent, if a child escalated a failure through [ActorResult::Escalate](../prelude/enum.ActorResult.html#variant.Escalate) or [SupervisionStrategy::Escalate](../prelude/enum.SupervisionStrategy.html#variant.Escalate)
+    #[error("Child actor {} escalated a failure", .child.actor)]
+    ChildEscalationError { child: ActorAddress },
 }
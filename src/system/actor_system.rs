@@ -1,16 +1,23 @@
 use crate::actor::actor_address::ActorAddress;
 use crate::actor::actor_builder::ActorBuilder;
+use crate::actor::actor_info::ActorInfo;
+use crate::actor::termination_handle::TerminationHandle;
+use crate::config::config_validation_error::ConfigValidationError;
 use crate::config::pool_config::ThreadPoolConfig;
-use crate::config::tyra_config::{TyraConfig, DEFAULT_POOL};
+use crate::config::tyra_config::{TyraConfig, DEFAULT_POOL, INTERNAL_POOL};
+use crate::message::dead_letter::DeadLetter;
+use crate::message::dead_letter_reason::DeadLetterReason;
 use crate::message::serialized_message::SerializedMessage;
-use crate::prelude::{Actor, ActorError, Handler};
+use crate::prelude::{Actor, ActorError, ActorWrapper, Handler};
 use crate::system::internal_actor_manager::InternalActorManager;
+use crate::system::shutdown_phase::ShutdownPhase;
 use crate::system::system_state::SystemState;
 use crate::system::thread_pool_manager::ThreadPoolManager;
+use crate::system::timer_wheel::TimerWheel;
 use crate::system::wakeup_manager::WakeupManager;
 use dashmap::DashMap;
+use log::error;
 use std::sync::Arc;
-use std::thread::sleep;
 use std::time::Duration;
 
 /// Manages thread pools and actors
@@ -37,7 +44,37 @@ impl ActorSystem {
     /// let actor_config = TyraConfig::new().unwrap();
     /// let actor_system = ActorSystem::new(actor_config);
     /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the configuration is invalid, use [try_new](#method.try_new) to handle [ConfigValidationError](../prelude/enum.ConfigValidationError.html) instead
     pub fn new(config: TyraConfig) -> Self {
+        match Self::try_new(config) {
+            Ok(system) => return system,
+            Err(errors) => {
+                let errors: Vec<String> = errors.iter().map(|error| error.to_string()).collect();
+                panic!("Invalid TyraConfig: {}", errors.join(", "));
+            }
+        }
+    }
+
+    /// Same as [new](#method.new), but returns every [ConfigValidationError](../prelude/enum.ConfigValidationError.html) instead of panicking if the configuration is invalid
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tyra::prelude::{TyraConfig, ActorSystem, ConfigValidationError};
+    ///
+    /// let mut actor_config = TyraConfig::new().unwrap();
+    /// actor_config.general.default_message_throughput = 0;
+    /// let result = ActorSystem::try_new(actor_config);
+    /// assert_eq!(result.err().unwrap(), vec![ConfigValidationError::InvalidMessageThroughput]);
+    ///
+    /// let actor_config = TyraConfig::new().unwrap();
+    /// let actor_system = ActorSystem::try_new(actor_config).unwrap();
+    /// ```
+    pub fn try_new(config: TyraConfig) -> Result<Self, Vec<ConfigValidationError>> {
+        config.validate()?;
         if config.general.override_panic_hook {
             std::panic::set_hook(Box::new(|_| {}));
         }
@@ -45,27 +82,30 @@ impl ActorSystem {
         let thread_pool_config = config.thread_pool.clone();
 
         let thread_pool_manager = ThreadPoolManager::new();
-        let wakeup_manager = WakeupManager::new();
+        let timer_wheel = TimerWheel::new();
+        let wakeup_manager = WakeupManager::new(thread_pool_manager.clone(), timer_wheel.clone());
 
         let thread_pool_max_actors = DashMap::new();
-
         for (key, value) in thread_pool_config.config.iter() {
-            thread_pool_manager.add_pool_with_config(key, value.clone());
             thread_pool_max_actors.insert(key.clone(), value.actor_limit);
         }
-        let state = SystemState::new(wakeup_manager.clone(), Arc::new(thread_pool_max_actors));
+        let state = SystemState::new(
+            wakeup_manager.clone(),
+            thread_pool_manager.clone(),
+            Arc::new(thread_pool_max_actors),
+        );
+        for (key, value) in thread_pool_config.config.iter() {
+            thread_pool_manager.add_pool_with_config(
+                key,
+                value.clone(),
+                state.clone(),
+                wakeup_manager.clone(),
+            );
+        }
 
+        let tw = timer_wheel.clone();
         let s = state.clone();
-        let t = thread_pool_manager.clone();
-        let w = wakeup_manager.clone();
-        std::thread::spawn(move || t.manage(s, w));
-        let s = state.clone();
-        let t = thread_pool_manager.clone();
-        let w = wakeup_manager.clone();
-        std::thread::spawn(move || w.manage_inactive(s, t));
-        let w = wakeup_manager.clone();
-        let s = state.clone();
-        std::thread::spawn(move || w.clone().manage_sleeping(s));
+        std::thread::spawn(move || tw.manage(s));
 
         let mut system = ActorSystem {
             state,
@@ -73,16 +113,97 @@ impl ActorSystem {
             wakeup_manager,
             name: config.general.name.clone(),
             config: Arc::new(config.clone()),
-            internal_actor_manager: InternalActorManager::new(),
+            internal_actor_manager: InternalActorManager::new(timer_wheel),
         };
 
         system.internal_actor_manager.init(system.clone());
 
-        system
+        if config.general.handle_signals {
+            let timeout =
+                Duration::from_millis(config.general.signal_graceful_termination_timeout_ms);
+            if let Err(e) = system.handle_signals(timeout) {
+                error!("Could not register signal handlers: {}", e);
+            }
+        }
+
+        return Ok(system);
+    }
+
+    /// Stops the system gracefully with `graceful_termination_timeout` once SIGINT or SIGTERM is received
+    ///
+    /// A second signal force stops the system without waiting for remaining actors or shutdown hooks, [await_shutdown](#method.await_shutdown) then returns `128 + signal`
+    ///
+    /// Automatically called by [new](#method.new) if `general.handle_signals` is enabled in the [TyraConfig](../prelude/struct.TyraConfig.html)
+    /// Signal handlers are only registered once, subsequent calls return `Ok(())` without any effect
+    /// Only supported on unix, fails with [std::io::ErrorKind::Unsupported] on other platforms
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tyra::prelude::{TyraConfig, ActorSystem};
+    /// use std::time::Duration;
+    ///
+    /// #[ntest::timeout(10000)]
+    /// fn main() {
+    ///     let actor_config = TyraConfig::new().unwrap();
+    ///     let actor_system = ActorSystem::new(actor_config);
+    ///     actor_system.handle_signals(Duration::from_secs(1)).unwrap();
+    ///     // already registered, the first signal still stops the system gracefully
+    ///     actor_system.handle_signals(Duration::from_secs(1)).unwrap();
+    ///
+    ///     #[cfg(unix)]
+    ///     {
+    ///         signal_hook::low_level::raise(signal_hook::consts::SIGTERM).unwrap();
+    ///         assert_eq!(actor_system.await_shutdown(), 0);
+    ///     }
+    /// }
+    /// ```
+    #[cfg(unix)]
+    pub fn handle_signals(&self, graceful_termination_timeout: Duration) -> std::io::Result<()> {
+        use signal_hook::consts::{SIGINT, SIGTERM};
+        use signal_hook::iterator::Signals;
+
+        if !self.state.register_signal_handling() {
+            return Ok(());
+        }
+        let mut signals = match Signals::new([SIGINT, SIGTERM]) {
+            Ok(signals) => signals,
+            Err(e) => {
+                self.state.unregister_signal_handling();
+                return Err(e);
+            }
+        };
+        let signals_handle = signals.handle();
+        self.register_shutdown_hook(ShutdownPhase::AfterActorStop, move || {
+            signals_handle.close()
+        });
+
+        let system = self.clone();
+        std::thread::spawn(move || {
+            for signal in signals.forever() {
+                if system.state.is_shutdown_requested() {
+                    system.state.use_forced_exit_code(128 + signal);
+                    system.state.force_stop();
+                    continue;
+                }
+                system.stop(graceful_termination_timeout);
+            }
+        });
+        return Ok(());
+    }
+
+    #[cfg(not(unix))]
+    pub fn handle_signals(&self, _graceful_termination_timeout: Duration) -> std::io::Result<()> {
+        return Err(std::io::Error::new(
+            std::io::ErrorKind::Unsupported,
+            "signal handling is only supported on unix",
+        ));
     }
 
     /// Adds a new named pool using the [default pool configuration](https://github.com/sers-dev/tyra/blob/master/src/config/default.toml)
     ///
+    /// Returns `ActorError::InvalidThreadPoolNameError` if the name is not [valid](../prelude/struct.ActorAddress.html#method.is_valid_name)
+    ///
     /// # Examples
     ///
     /// Basic usage:
@@ -92,9 +213,10 @@ impl ActorSystem {
     ///
     /// let actor_config = TyraConfig::new().unwrap();
     /// let actor_system = ActorSystem::new(actor_config);
-    /// actor_system.add_pool("test");
+    /// actor_system.add_pool("test").unwrap();
+    /// assert!(actor_system.add_pool("te/st").is_err());
     /// ```
-    pub fn add_pool(&self, name: &str) {
+    pub fn add_pool(&self, name: &str) -> Result<(), ActorError> {
         let default_config = self.config.thread_pool.config.get(DEFAULT_POOL).unwrap();
         let config = self
             .config
@@ -102,11 +224,13 @@ impl ActorSystem {
             .config
             .get(name)
             .unwrap_or(default_config);
-        self.add_pool_with_config(name, config.clone());
+        return self.add_pool_with_config(name, config.clone());
     }
 
     /// Adds a new named pool with custom [pool configuration](../prelude/struct.ThreadPoolConfig.html)
     ///
+    /// Returns `ActorError::InvalidThreadPoolNameError` if the name is not [valid](../prelude/struct.ActorAddress.html#method.is_valid_name)
+    ///
     /// # Examples
     ///
     /// Basic usage:
@@ -117,13 +241,169 @@ impl ActorSystem {
     /// let actor_config = TyraConfig::new().unwrap();
     /// let actor_system = ActorSystem::new(actor_config);
     /// let pool_config = ThreadPoolConfig::new(0, 2, 4, 1.0);
-    /// actor_system.add_pool_with_config("test", pool_config);
+    /// actor_system.add_pool_with_config("test", pool_config).unwrap();
     /// ```
-    pub fn add_pool_with_config(&self, name: &str, thread_pool_config: ThreadPoolConfig) {
+    pub fn add_pool_with_config(
+        &self,
+        name: &str,
+        thread_pool_config: ThreadPoolConfig,
+    ) -> Result<(), ActorError> {
+        if !ActorAddress::is_valid_name(name) {
+            return Err(ActorError::InvalidThreadPoolNameError {
+                name: String::from(name),
+            });
+        }
         self.state
             .add_pool_actor_limit(String::from(name.clone()), thread_pool_config.actor_limit);
-        self.thread_pool_manager
-            .add_pool_with_config(name, thread_pool_config);
+        self.thread_pool_manager.add_pool_with_config(
+            name,
+            thread_pool_config,
+            self.state.clone(),
+            self.wakeup_manager.clone(),
+        );
+        return Ok(());
+    }
+
+    /// Reconfigures an existing thread_pool at runtime
+    ///
+    /// Threads are added immediately, surplus threads return as soon as they finished handling their current actor
+    /// Lowering the `actor_limit` below the current amount of actors does not stop any actors, but prevents new actors from being spawned
+    ///
+    /// Returns `ActorError::ThreadPoolDoesNotExistError` if the pool does not exist and `ActorError::InvalidThreadPoolConfigError` if the config is invalid
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tyra::prelude::{TyraConfig, ActorSystem, ThreadPoolConfig, ActorError};
+    ///
+    /// let actor_config = TyraConfig::new().unwrap();
+    /// let actor_system = ActorSystem::new(actor_config);
+    /// actor_system.add_pool_with_config("test", ThreadPoolConfig::new(2, 1, 1, 1.0)).unwrap();
+    /// assert_eq!(actor_system.get_available_actor_count_for_pool("test").unwrap(), 2);
+    ///
+    /// actor_system.update_pool_config("test", ThreadPoolConfig::new(5, 4, 4, 1.0)).unwrap();
+    /// assert_eq!(actor_system.get_available_actor_count_for_pool("test").unwrap(), 5);
+    /// assert_eq!(actor_system.get_pool_config("test").unwrap().threads_min, 4);
+    ///
+    /// let result = actor_system.update_pool_config("does-not-exist", ThreadPoolConfig::new(5, 4, 4, 1.0));
+    /// assert_eq!(result, Err(ActorError::ThreadPoolDoesNotExistError));
+    /// let result = actor_system.update_pool_config("test", ThreadPoolConfig::new(5, 4, 4, 0.0));
+    /// assert!(result.is_err());
+    /// ```
+    pub fn update_pool_config(
+        &self,
+        name: &str,
+        thread_pool_config: ThreadPoolConfig,
+    ) -> Result<(), ActorError> {
+        let result = thread_pool_config.validate(name);
+        if result.is_err() {
+            return Err(ActorError::InvalidThreadPoolConfigError {
+                errors: result.unwrap_err(),
+            });
+        }
+        let actor_limit = thread_pool_config.actor_limit;
+        self.thread_pool_manager.update_pool_config(
+            name,
+            thread_pool_config,
+            self.state.clone(),
+            self.wakeup_manager.clone(),
+        )?;
+        return self.state.update_pool_actor_limit(name, actor_limit);
+    }
+
+    /// Stops all actors of the thread_pool and removes it, including all of its threads
+    ///
+    /// Actors that did not stop within the `graceful_termination_timeout` are killed, the remaining time is used to wait for the threads to return
+    /// New actors can't be spawned on the pool as soon as the removal started
+    ///
+    /// Returns `ActorError::ThreadPoolDoesNotExistError` if the pool does not exist and `ActorError::RequiredThreadPoolError` for the `default` and the internal `tyra` pool
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tyra::prelude::*;
+    /// use std::error::Error;
+    /// use std::sync::{Arc, Barrier};
+    /// use std::time::Duration;
+    ///
+    /// struct TestActor {}
+    /// impl Actor for TestActor {}
+    ///
+    /// struct TestActorFactory {}
+    /// impl ActorFactory<TestActor> for TestActorFactory {
+    ///     fn new_actor(&mut self, _context: ActorContext<TestActor>) -> Result<TestActor, Box<dyn Error>> {
+    ///         Ok(TestActor {})
+    ///     }
+    /// }
+    ///
+    /// struct Pause {
+    ///     started: Arc<Barrier>,
+    ///     resume: Arc<Barrier>,
+    /// }
+    /// impl ActorMessage for Pause {}
+    ///
+    /// impl Handler<Pause> for TestActor {
+    ///     fn handle(&mut self, msg: Pause, _context: &ActorContext<Self>) -> Result<ActorResult, Box<dyn Error>> {
+    ///         msg.started.wait();
+    ///         msg.resume.wait();
+    ///         Ok(ActorResult::Ok)
+    ///     }
+    /// }
+    ///
+    /// #[ntest::timeout(10000)]
+    /// fn main() {
+    ///     let actor_config = TyraConfig::new().unwrap();
+    ///     let actor_system = ActorSystem::new(actor_config);
+    ///     actor_system.add_pool_with_config("tenant", ThreadPoolConfig::new(0, 1, 1, 1.0)).unwrap();
+    ///     let actor = actor_system.builder().set_pool_name("tenant").spawn("test", TestActorFactory {}).unwrap();
+    ///
+    ///     actor_system.remove_pool("tenant", Duration::from_secs(1)).unwrap();
+    ///     assert_eq!(actor.get_termination_handle().get_termination_reason(), Some(TerminationReason::Stopped));
+    ///     assert!(actor_system.get_pool_config("tenant").is_none());
+    ///
+    ///     let result = actor_system.builder().set_pool_name("tenant").spawn("test", TestActorFactory {});
+    ///     assert_eq!(result.err().unwrap(), ActorError::ThreadPoolDoesNotExistError);
+    ///     let result = actor_system.remove_pool("tyra", Duration::from_secs(1));
+    ///     assert_eq!(result, Err(ActorError::RequiredThreadPoolError));
+    ///     let result = actor_system.remove_pool("default", Duration::from_secs(1));
+    ///     assert_eq!(result, Err(ActorError::RequiredThreadPoolError));
+    ///
+    ///     // the pool can't be reconfigured while it is being removed
+    ///     actor_system.add_pool_with_config("blocked", ThreadPoolConfig::new(0, 1, 1, 1.0)).unwrap();
+    ///     let actor = actor_system.builder().set_pool_name("blocked").spawn("test", TestActorFactory {}).unwrap();
+    ///     let started = Arc::new(Barrier::new(2));
+    ///     let resume = Arc::new(Barrier::new(2));
+    ///     actor.send(Pause { started: started.clone(), resume: resume.clone() }).unwrap();
+    ///     started.wait();
+    ///     let system = actor_system.clone();
+    ///     let removal = std::thread::spawn(move || system.remove_pool("blocked", Duration::from_secs(5)));
+    ///     while actor_system.get_available_actor_count_for_pool("blocked").is_ok() {
+    ///         std::thread::yield_now();
+    ///     }
+    ///     let result = actor_system.update_pool_config("blocked", ThreadPoolConfig::new(5, 1, 1, 1.0));
+    ///     assert_eq!(result, Err(ActorError::ThreadPoolDoesNotExistError));
+    ///     resume.wait();
+    ///     removal.join().unwrap().unwrap();
+    ///     assert!(actor_system.get_available_actor_count_for_pool("blocked").is_err());
+    ///
+    ///     actor_system.stop(Duration::from_millis(1000));
+    ///     std::process::exit(actor_system.await_shutdown());
+    /// }
+    /// ```
+    pub fn remove_pool(
+        &self,
+        name: &str,
+        graceful_termination_timeout: Duration,
+    ) -> Result<(), ActorError> {
+        if name == DEFAULT_POOL || name == INTERNAL_POOL {
+            return Err(ActorError::RequiredThreadPoolError);
+        }
+        return self.state.remove_pool(name, graceful_termination_timeout);
+    }
+
+    /// Returns the current [ThreadPoolConfig](../prelude/struct.ThreadPoolConfig.html) of the thread_pool, or `None` if it does not exist
+    pub fn get_pool_config(&self, name: &str) -> Option<ThreadPoolConfig> {
+        return self.thread_pool_manager.get_pool_config(name);
     }
 
     /// Returns the amount of Actors that can still be put onto a given thread_pool
@@ -183,7 +463,81 @@ impl ActorSystem {
     /// actor_system.send_to_address(address, SerializedMessage::new(Vec::new()));
     /// ```
     pub fn send_to_address(&self, address: &ActorAddress, msg: SerializedMessage) {
-        self.state.send_to_address(address, msg);
+        let is_delivered = self.state.send_to_address(address, msg);
+        if !is_delivered {
+            self.send_dead_letter(DeadLetter::new(
+                address.clone(),
+                std::any::type_name::<SerializedMessage>(),
+                DeadLetterReason::UnknownAddress,
+            ));
+        }
+    }
+
+    /// Subscribes an actor to all [DeadLetter](../prelude/struct.DeadLetter.html)s of the system
+    ///
+    /// Dead letters are published for messages sent to stopped actors, messages dropped when an actor is killed, messages sent to unknown addresses and messages sent to routers without routees
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tyra::prelude::*;
+    /// use std::error::Error;
+    /// use std::time::Duration;
+    ///
+    /// struct TestActor {}
+    /// impl Actor for TestActor {}
+    ///
+    /// struct TestActorFactory {}
+    /// impl ActorFactory<TestActor> for TestActorFactory {
+    ///     fn new_actor(&mut self, _context: ActorContext<TestActor>) -> Result<TestActor, Box<dyn Error>> {
+    ///         Ok(TestActor {})
+    ///     }
+    /// }
+    ///
+    /// struct FooBar {}
+    /// impl ActorMessage for FooBar {}
+    ///
+    /// impl Handler<FooBar> for TestActor {
+    ///     fn handle(&mut self, _msg: FooBar, _context: &ActorContext<Self>) -> Result<ActorResult, Box<dyn Error>> {
+    ///         Ok(ActorResult::Ok)
+    ///     }
+    /// }
+    ///
+    /// impl Handler<DeadLetter> for TestActor {
+    ///     fn handle(&mut self, msg: DeadLetter, context: &ActorContext<Self>) -> Result<ActorResult, Box<dyn Error>> {
+    ///         assert_eq!("target", msg.address.actor);
+    ///         assert!(msg.message_type.ends_with("FooBar"));
+    ///         assert_eq!(DeadLetterReason::ActorStopped, msg.reason);
+    ///         context.system.stop(Duration::from_millis(1000));
+    ///         Ok(ActorResult::Ok)
+    ///     }
+    /// }
+    ///
+    /// #[ntest::timeout(10000)]
+    /// fn main() {
+    ///     let actor_config = TyraConfig::new().unwrap();
+    ///     let actor_system = ActorSystem::new(actor_config);
+    ///     let subscriber = actor_system.builder().spawn("subscriber", TestActorFactory {}).unwrap();
+    ///     actor_system.subscribe_dead_letters(&subscriber);
+    ///
+    ///     let target = actor_system.builder().spawn("target", TestActorFactory {}).unwrap();
+    ///     target.stop().unwrap();
+    ///     while target.send(FooBar {}).is_ok() {
+    ///         std::thread::sleep(Duration::from_millis(10));
+    ///     }
+    ///     std::process::exit(actor_system.await_shutdown());
+    /// }
+    /// ```
+    pub fn subscribe_dead_letters<A>(&self, subscriber: &ActorWrapper<A>)
+    where
+        A: Handler<DeadLetter> + 'static,
+    {
+        self.internal_actor_manager
+            .subscribe_dead_letters(subscriber.clone());
+    }
+
+    pub(crate) fn send_dead_letter(&self, dead_letter: DeadLetter) {
+        self.internal_actor_manager.send_dead_letter(dead_letter);
     }
 
     /// Returns a Builder to configure and spawn an actor in the system
@@ -226,10 +580,157 @@ impl ActorSystem {
         )
     }
 
+    /// Returns an [ActorWrapper](../prelude/struct.ActorWrapper.html) for an existing actor
+    ///
+    /// Fails with [ActorError::ActorDoesNotExistError](../prelude/enum.ActorError.html#variant.ActorDoesNotExistError) if there's no actor with the given address,
+    /// or with [ActorError::InvalidActorTypeError](../prelude/enum.ActorError.html#variant.InvalidActorTypeError) if the actor is not of type `A`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::error::Error;
+    /// use tyra::prelude::*;
+    ///
+    /// struct TestActor {}
+    /// impl Actor for TestActor {}
+    ///
+    /// struct OtherActor {}
+    /// impl Actor for OtherActor {}
+    ///
+    /// struct TestActorFactory {}
+    /// impl ActorFactory<TestActor> for TestActorFactory {
+    ///     fn new_actor(&mut self, _context: ActorContext<TestActor>) -> Result<TestActor, Box<dyn Error>> {
+    ///         Ok(TestActor {})
+    ///     }
+    /// }
+    ///
+    /// let actor_config = TyraConfig::new().unwrap();
+    /// let actor_system = ActorSystem::new(actor_config);
+    /// let actor = actor_system.builder().spawn("test", TestActorFactory {}).unwrap();
+    ///
+    /// let same_actor = actor_system.get_actor::<TestActor>(actor.get_address()).unwrap();
+    /// assert_eq!(actor.get_address(), same_actor.get_address());
+    ///
+    /// let same_actor = actor_system.get_actor_by_name::<TestActor>("default", "test").unwrap();
+    /// assert_eq!(actor.get_address(), same_actor.get_address());
+    ///
+    /// let err = actor_system.get_actor::<OtherActor>(actor.get_address()).err().unwrap();
+    /// assert_eq!(err, ActorError::InvalidActorTypeError);
+    ///
+    /// let err = actor_system.get_actor_by_name::<TestActor>("default", "unknown").err().unwrap();
+    /// assert_eq!(err, ActorError::ActorDoesNotExistError);
+    /// ```
+    pub fn get_actor<A>(&self, address: &ActorAddress) -> Result<ActorWrapper<A>, ActorError>
+    where
+        A: Handler<SerializedMessage> + Actor + 'static,
+    {
+        return self
+            .state
+            .get_actor_ref(address.clone(), self.internal_actor_manager.clone());
+    }
+
+    /// Same as [get_actor](#method.get_actor), but looks up the actor by the name of its thread-pool and its own name
+    pub fn get_actor_by_name<A>(
+        &self,
+        pool: &str,
+        name: &str,
+    ) -> Result<ActorWrapper<A>, ActorError>
+    where
+        A: Handler<SerializedMessage> + Actor + 'static,
+    {
+        let address = ActorAddress {
+            actor: String::from(name),
+            system: self.name.clone(),
+            pool: String::from(pool),
+            remote: String::from("local"),
+        };
+        return self.get_actor(&address);
+    }
+
+    /// Returns a snapshot of all actors that are currently running within the system, including internal actors
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::error::Error;
+    /// use tyra::prelude::*;
+    ///
+    /// struct TestActor {}
+    /// impl Actor for TestActor {}
+    ///
+    /// struct TestActorFactory {}
+    /// impl ActorFactory<TestActor> for TestActorFactory {
+    ///     fn new_actor(&mut self, _context: ActorContext<TestActor>) -> Result<TestActor, Box<dyn Error>> {
+    ///         Ok(TestActor {})
+    ///     }
+    /// }
+    ///
+    /// let actor_config = TyraConfig::new().unwrap();
+    /// let actor_system = ActorSystem::new(actor_config);
+    /// actor_system.builder().spawn("test", TestActorFactory {}).unwrap();
+    ///
+    /// let actor = actor_system.list_actors().find(|info| info.address.actor == "test").unwrap();
+    /// assert_eq!(actor.get_pool(), "default");
+    /// assert_ne!(actor.state, ActorState::Stopped);
+    /// ```
+    pub fn list_actors(&self) -> impl Iterator<Item = ActorInfo> {
+        return self.state.get_actor_infos().into_iter();
+    }
+
+    /// Tells the actor with the given address to stop, regardless of its type
+    ///
+    /// Returns a [TerminationHandle](../prelude/struct.TerminationHandle.html) that resolves once the actor has terminated and was removed from the system,
+    /// or [ActorError::ActorDoesNotExistError](../prelude/enum.ActorError.html#variant.ActorDoesNotExistError) if there's no actor with the given address
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::error::Error;
+    /// use std::time::Duration;
+    /// use tyra::prelude::*;
+    ///
+    /// struct TestActor {}
+    /// impl Actor for TestActor {}
+    ///
+    /// struct TestActorFactory {}
+    /// impl ActorFactory<TestActor> for TestActorFactory {
+    ///     fn new_actor(&mut self, _context: ActorContext<TestActor>) -> Result<TestActor, Box<dyn Error>> {
+    ///         Ok(TestActor {})
+    ///     }
+    /// }
+    ///
+    /// #[ntest::timeout(10000)]
+    /// fn main() {
+    ///     let actor_config = TyraConfig::new().unwrap();
+    ///     let actor_system = ActorSystem::new(actor_config);
+    ///     let actor = actor_system.builder().spawn("test", TestActorFactory {}).unwrap();
+    ///
+    ///     let termination_handle = actor_system.stop_actor(actor.get_address()).unwrap();
+    ///     let reason = termination_handle.wait_timeout(Duration::from_secs(5)).unwrap();
+    ///     assert_eq!(reason, TerminationReason::Stopped);
+    ///
+    ///     let err = actor_system.stop_actor(actor.get_address()).err().unwrap();
+    ///     assert_eq!(err, ActorError::ActorDoesNotExistError);
+    ///
+    ///     actor_system.stop(Duration::from_millis(1000));
+    ///     std::process::exit(actor_system.await_shutdown());
+    /// }
+    /// ```
+    pub fn stop_actor(&self, address: &ActorAddress) -> Result<TerminationHandle, ActorError> {
+        return self.state.stop_actor(address);
+    }
+
+    pub(crate) fn get_state(&self) -> &SystemState {
+        return &self.state;
+    }
+
     /// Sends a SystemStopMessage to all running Actors, and wakes them up if necessary.
     /// Users can implement their own clean system stop behavior, by implementing [Actor.on_system_stop](../prelude/trait.Actor.html#method.on_system_stop) and [Actor.on_actor_stop](../prelude/trait.Actor.html#method.on_actor_stop)
     ///
-    /// System will stop after all actors have been stopped or after `graceful_termination_timeout`
+    /// Actors are stopped phase by phase in ascending order of their [shutdown phase](../prelude/struct.ActorBuilder.html#method.set_shutdown_phase)
+    /// Actors that did not stop within `graceful_termination_timeout` after their phase started are force stopped, see [get_force_stopped_actors](#method.get_force_stopped_actors)
+    ///
+    /// System will stop after all actors have been stopped or force stopped
     ///
     /// # Examples
     ///
@@ -291,12 +792,55 @@ impl ActorSystem {
     /// exit(actor_system.await_shutdown());
     /// ```
     pub fn await_shutdown(&self) -> i32 {
-        while !self.state.is_stopped() {
-            sleep(Duration::from_millis(500));
-        }
+        self.state.await_stopped();
         return self.state.get_exit_code();
     }
 
+    /// Returns the addresses of all actors that were force stopped during [stop](#method.stop), because they did not stop within the `graceful_termination_timeout` of their shutdown phase
+    pub fn get_force_stopped_actors(&self) -> Vec<ActorAddress> {
+        return self.state.get_force_stopped_actors();
+    }
+
+    /// Registers a hook that is executed during [stop](#method.stop) in the given [ShutdownPhase](../prelude/enum.ShutdownPhase.html)
+    ///
+    /// Hooks of a phase are executed sequentially in the order they were registered
+    /// Each phase is limited by the `graceful_termination_timeout` of [stop](#method.stop), remaining hooks of the phase are skipped once the timeout is reached
+    /// Hooks that are registered after the phase has started are never executed
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tyra::prelude::{TyraConfig, ActorSystem, ShutdownPhase};
+    /// use std::sync::{Arc, Mutex};
+    /// use std::time::Duration;
+    ///
+    /// #[ntest::timeout(10000)]
+    /// fn main() {
+    ///     let actor_config = TyraConfig::new().unwrap();
+    ///     let actor_system = ActorSystem::new(actor_config);
+    ///
+    ///     let executed = Arc::new(Mutex::new(Vec::new()));
+    ///     let e = executed.clone();
+    ///     actor_system.register_shutdown_hook(ShutdownPhase::AfterActorStop, move || {
+    ///         e.lock().unwrap().push("flush metrics");
+    ///     });
+    ///     let e = executed.clone();
+    ///     actor_system.register_shutdown_hook(ShutdownPhase::BeforeActorStop, move || {
+    ///         e.lock().unwrap().push("deregister");
+    ///     });
+    ///
+    ///     actor_system.stop(Duration::from_secs(1));
+    ///     assert_eq!(actor_system.await_shutdown(), 0);
+    ///     assert_eq!(*executed.lock().unwrap(), vec!["deregister", "flush metrics"]);
+    /// }
+    /// ```
+    pub fn register_shutdown_hook<F>(&self, phase: ShutdownPhase, hook: F)
+    where
+        F: FnOnce() + Send + 'static,
+    {
+        self.state.register_shutdown_hook(phase, Box::new(hook));
+    }
+
     /// Returns a reference to the [TyraConfig](../prelude/struct.TyraConfig.html)
     ///
     /// # Examples
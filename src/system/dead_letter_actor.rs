@@ -0,0 +1,91 @@
+use crate::actor::actor_address::ActorAddress;
+use crate::message::dead_letter::DeadLetter;
+use crate::message::dead_letter_subscription_message::DeadLetterSubscriptionMessage;
+use crate::prelude::{
+    Actor, ActorContext, ActorFactory, ActorResult, ActorSendError, ActorTerminated, Handler,
+};
+use log::debug;
+use std::error::Error;
+use std::panic::UnwindSafe;
+
+struct DeadLetterSubscriber {
+    address: ActorAddress,
+    send: Box<dyn Fn(DeadLetter) -> Result<(), ActorSendError> + Send + Sync>,
+}
+
+pub struct DeadLetterActor {
+    subscribers: Vec<DeadLetterSubscriber>,
+}
+
+impl UnwindSafe for DeadLetterActor {}
+
+impl Actor for DeadLetterActor {
+    fn on_actor_terminated(
+        &mut self,
+        _context: &ActorContext<Self>,
+        msg: ActorTerminated,
+    ) -> Result<ActorResult, Box<dyn Error>> {
+        self.subscribers
+            .retain(|subscriber| subscriber.address != msg.address);
+        return Ok(ActorResult::Ok);
+    }
+}
+
+impl DeadLetterActor {
+    fn new() -> Self {
+        Self {
+            subscribers: Vec::new(),
+        }
+    }
+}
+
+pub struct DeadLetterActorFactory {}
+impl ActorFactory<DeadLetterActor> for DeadLetterActorFactory {
+    fn new_actor(
+        &mut self,
+        _context: ActorContext<DeadLetterActor>,
+    ) -> Result<DeadLetterActor, Box<dyn Error>> {
+        return Ok(DeadLetterActor::new());
+    }
+}
+impl DeadLetterActorFactory {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Handler<DeadLetter> for DeadLetterActor {
+    fn handle(
+        &mut self,
+        msg: DeadLetter,
+        _context: &ActorContext<Self>,
+    ) -> Result<ActorResult, Box<dyn Error>> {
+        debug!(
+            "Dead letter {} to target {}: {:?}",
+            msg.message_type, msg.address.actor, msg.reason
+        );
+        // subscribers that can't be reached anymore are removed, to not produce further dead letters
+        self.subscribers
+            .retain(|subscriber| (subscriber.send)(msg.clone()).is_ok());
+        return Ok(ActorResult::Ok);
+    }
+}
+
+impl<A> Handler<DeadLetterSubscriptionMessage<A>> for DeadLetterActor
+where
+    A: Handler<DeadLetter> + 'static,
+{
+    fn handle(
+        &mut self,
+        msg: DeadLetterSubscriptionMessage<A>,
+        context: &ActorContext<Self>,
+    ) -> Result<ActorResult, Box<dyn Error>> {
+        context.watch(&msg.subscriber);
+        let subscriber = msg.subscriber;
+        self.subscribers.push(DeadLetterSubscriber {
+            address: subscriber.get_address().clone(),
+            send: Box::new(move |dead_letter| subscriber.send(dead_letter)),
+        });
+        return Ok(ActorResult::Ok);
+    }
+}
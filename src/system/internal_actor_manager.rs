@@ -1,56 +1,139 @@
+use crate::actor::actor_send_error::ActorSendError;
+use crate::actor::mailbox_overflow_strategy::MailboxOverflowStrategy;
+use crate::config::actor_deployment_config::ActorDeploymentConfig;
+use crate::config::tyra_config::INTERNAL_POOL;
 use crate::message::actor_message::BaseActorMessage;
-use crate::message::delayed_message::DelayedMessage;
-use crate::prelude::{ActorSystem, ActorWrapper, Handler};
-use crate::router::{AddActorMessage, RoundRobinRouter, RoundRobinRouterFactory};
-use crate::system::delay_actor::{DelayActor, DelayActorFactory};
-use log::error;
+use crate::message::dead_letter::DeadLetter;
+use crate::message::dead_letter_subscription_message::DeadLetterSubscriptionMessage;
+use crate::prelude::{ActorSystem, ActorWrapper, Handler, TimerHandle};
+use crate::system::dead_letter_actor::{DeadLetterActor, DeadLetterActorFactory};
+use crate::system::timer_wheel::TimerWheel;
+use log::{error, warn};
+use std::any::type_name;
 use std::time::Duration;
 
+/// amount of actors that are spawned on the internal thread_pool
+pub const INTERNAL_ACTOR_COUNT: usize = 1;
+
 #[derive(Clone)]
 pub struct InternalActorManager {
-    delay_router: Option<ActorWrapper<RoundRobinRouter<DelayActor>>>,
+    timer_wheel: TimerWheel,
+    dead_letter_actor: Option<ActorWrapper<DeadLetterActor>>,
 }
 
 impl InternalActorManager {
-    pub fn new() -> Self {
-        Self { delay_router: None }
+    pub fn new(timer_wheel: TimerWheel) -> Self {
+        Self {
+            timer_wheel,
+            dead_letter_actor: None,
+        }
     }
     pub fn init(&mut self, system: ActorSystem) {
-        let delay_builder = system
-            .builder()
-            .set_pool_name("tyra")
-            .set_mailbox_unbounded();
-        let delay_router = system
+        // the `[actors]` section of the config must not move the actor off the internal pool, out of the last phase or bound its mailbox
+        let forced_config = ActorDeploymentConfig {
+            pool_name: Some(String::from(INTERNAL_POOL)),
+            // senders of dead letters must never block or lose them
+            mailbox_size: Some(0),
+            mailbox_overflow_strategy: Some(MailboxOverflowStrategy::Block),
+            // dead letters are collected until all other actors stopped
+            shutdown_phase: Some(usize::MAX),
+            ..Default::default()
+        };
+        let dead_letter_actor = system
             .builder()
-            .set_pool_name("tyra")
-            .set_mailbox_unbounded()
-            .spawn("delay-router", RoundRobinRouterFactory::new())
+            .set_forced_config(forced_config)
+            .spawn("dead-letters", DeadLetterActorFactory::new())
             .unwrap();
-        let remaining_actors = system.get_available_actor_count_for_pool("tyra").unwrap();
-        for i in 0..remaining_actors {
-            let delay_actor = delay_builder
-                .spawn(format!("delay-{}", i), DelayActorFactory::new())
-                .unwrap();
-            let result = delay_router.send(AddActorMessage::new(delay_actor));
-            if result.is_err() {
-                error!("Could not add delay_actor to delay_router");
-            }
-        }
-        self.delay_router = Some(delay_router);
+        self.dead_letter_actor = Some(dead_letter_actor);
+    }
+
+    pub fn send_after<A, M>(
+        &self,
+        msg: M,
+        destination: ActorWrapper<A>,
+        duration: Duration,
+    ) -> TimerHandle
+    where
+        M: BaseActorMessage + 'static,
+        A: Handler<M> + 'static,
+    {
+        let mut msg = Some(msg);
+        return self.timer_wheel.schedule_once(
+            move || {
+                // the timer thread must never block, otherwise all timers of the system are delayed
+                let result = destination.send_non_blocking(msg.take().unwrap());
+                if result.is_err() {
+                    error!(
+                        "Could not delay message to target {}",
+                        destination.get_address().actor
+                    );
+                    return false;
+                }
+                return true;
+            },
+            duration,
+        );
     }
 
-    pub fn send_after<A, M>(&self, msg: M, destination: ActorWrapper<A>, duration: Duration)
+    pub fn send_interval<A, M, F>(
+        &self,
+        msg_factory: F,
+        destination: ActorWrapper<A>,
+        period: Duration,
+    ) -> TimerHandle
     where
         M: BaseActorMessage + 'static,
         A: Handler<M> + 'static,
+        F: Fn() -> M + Send + Sync + 'static,
     {
+        return self.timer_wheel.schedule_interval(
+            move || {
+                // the timer thread must never block, otherwise all timers of the system are delayed
+                let result = destination.send_non_blocking(msg_factory());
+                if result == Err(ActorSendError::MailboxFullError) {
+                    // the interval continues, the message of this period is dropped
+                    warn!(
+                        "Could not send interval message to target {}, because the mailbox is full",
+                        destination.get_address().actor
+                    );
+                    return true;
+                }
+                if result.is_err() {
+                    error!(
+                        "Could not send interval message to target {}",
+                        destination.get_address().actor
+                    );
+                    return false;
+                }
+                return true;
+            },
+            period,
+        );
+    }
+
+    pub fn send_dead_letter(&self, dead_letter: DeadLetter) {
+        // dead letters about dead letters would loop forever
+        if self.dead_letter_actor.is_none() || dead_letter.message_type == type_name::<DeadLetter>()
+        {
+            return;
+        }
+        let _ = self.dead_letter_actor.as_ref().unwrap().send(dead_letter);
+    }
+
+    pub fn subscribe_dead_letters<A>(&self, subscriber: ActorWrapper<A>)
+    where
+        A: Handler<DeadLetter> + 'static,
+    {
+        if self.dead_letter_actor.is_none() {
+            return;
+        }
         let result = self
-            .delay_router
+            .dead_letter_actor
             .as_ref()
             .unwrap()
-            .send(DelayedMessage::new(msg, destination, duration));
+            .send(DeadLetterSubscriptionMessage::new(subscriber));
         if result.is_err() {
-            error!("Could not send message to delay router");
+            error!("Could not subscribe to dead letters");
         }
     }
 }
@@ -1,12 +1,17 @@
 pub mod actor_error;
 pub mod actor_system;
-pub mod delay_actor;
+pub mod dead_letter_actor;
 pub mod internal_actor_manager;
+pub mod shutdown_phase;
 pub mod system_state;
 mod thread_pool_manager;
+pub mod timer_handle;
+pub mod timer_wheel;
 pub mod wakeup_manager;
 
 pub mod prelude {
     pub use crate::system::actor_error::ActorError;
     pub use crate::system::actor_system::ActorSystem;
+    pub use crate::system::shutdown_phase::ShutdownPhase;
+    pub use crate::system::timer_handle::TimerHandle;
 }
@@ -0,0 +1,10 @@
+/// Defines when a hook registered through [ActorSystem.register_shutdown_hook](../prelude/struct.ActorSystem.html#method.register_shutdown_hook) is executed during [ActorSystem.stop](../prelude/struct.ActorSystem.html#method.stop)
+///
+/// Phases are executed in the order they are defined here, hooks within a phase are executed in the order they were registered
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub enum ShutdownPhase {
+    /// executed before any actor is told to stop, i.e. to deregister from service discovery
+    BeforeActorStop,
+    /// executed after all actors stopped or were force stopped, i.e. to flush metrics or to close database pools
+    AfterActorStop,
+}
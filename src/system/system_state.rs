@@ -1,25 +1,56 @@
 use crate::actor::actor_address::ActorAddress;
+use crate::actor::actor_info::ActorInfo;
 use crate::actor::mailbox::{BaseMailbox, Mailbox};
+use crate::actor::supervision_strategy::SupervisionStrategy;
+use crate::actor::termination_handle::TerminationHandle;
+use crate::actor::termination_reason::TerminationReason;
 use crate::message::serialized_message::SerializedMessage;
 use crate::prelude::{ActorWrapper, Handler};
 use crate::system::actor_error::ActorError;
 use crate::system::internal_actor_manager::InternalActorManager;
+use crate::system::shutdown_phase::ShutdownPhase;
+use crate::system::thread_pool_manager::ThreadPoolManager;
 use crate::system::wakeup_manager::WakeupManager;
 use dashmap::DashMap;
+use log::error;
+use std::collections::BTreeMap;
 use std::sync::atomic::{AtomicBool, AtomicI32, AtomicUsize, Ordering};
-use std::sync::Arc;
-use std::thread::sleep;
+use std::sync::{Arc, Condvar, Mutex};
 use std::time::{Duration, Instant};
 
+pub type ShutdownHook = Box<dyn FnOnce() + Send>;
+
 #[derive(Clone)]
 pub struct SystemState {
     mailboxes: Arc<DashMap<ActorAddress, Arc<dyn BaseMailbox>>>,
+    parents: Arc<DashMap<ActorAddress, ActorAddress>>,
+    children: Arc<DashMap<ActorAddress, Vec<ActorAddress>>>,
+    supervision_strategies: Arc<DashMap<ActorAddress, SupervisionStrategy>>,
+    watchers: Arc<DashMap<ActorAddress, Vec<ActorAddress>>>,
+    /// reverse index of `watchers`, contains all targets of a watcher
+    watched_targets: Arc<DashMap<ActorAddress, Vec<ActorAddress>>>,
     wakeup_manager: WakeupManager,
+    thread_pool_manager: ThreadPoolManager,
     total_actor_count: Arc<AtomicUsize>,
     pool_actor_count: Arc<DashMap<String, AtomicUsize>>,
     max_actors_per_pool: Arc<DashMap<String, usize>>,
     is_stopped: Arc<AtomicBool>,
     is_stopping: Arc<AtomicBool>,
+    is_shutdown_requested: Arc<AtomicBool>,
+    is_force_stop_requested: Arc<AtomicBool>,
+    /// dropped by [force_stop](#method.force_stop) to wake up running shutdown hooks
+    force_stop_sender: Arc<Mutex<Option<flume::Sender<()>>>>,
+    force_stop_receiver: flume::Receiver<()>,
+    is_signal_handling_registered: Arc<AtomicBool>,
+    shutdown_phases: Arc<DashMap<ActorAddress, usize>>,
+    /// amount of registered actors per shutdown phase, phases without actors are removed
+    phase_actor_count: Arc<Mutex<BTreeMap<usize, usize>>>,
+    /// all actors with a lower or equal phase are told to stop once the system is stopping
+    stopping_phase: Arc<AtomicUsize>,
+    force_stopped_actors: Arc<Mutex<Vec<ActorAddress>>>,
+    /// notified whenever an actor is removed or the system stopped
+    state_changed: Arc<(Mutex<()>, Condvar)>,
+    shutdown_hooks: Arc<Mutex<Vec<(ShutdownPhase, ShutdownHook)>>>,
     is_force_stopped: Arc<AtomicBool>,
     forced_exit_code: Arc<AtomicI32>,
     use_forced_exit_code: Arc<AtomicBool>,
@@ -28,16 +59,35 @@ pub struct SystemState {
 impl SystemState {
     pub fn new(
         wakeup_manager: WakeupManager,
+        thread_pool_manager: ThreadPoolManager,
         max_actors_per_pool: Arc<DashMap<String, usize>>,
     ) -> Self {
+        let (force_stop_sender, force_stop_receiver) = flume::bounded(0);
         Self {
             mailboxes: Arc::new(DashMap::new()),
+            parents: Arc::new(DashMap::new()),
+            children: Arc::new(DashMap::new()),
+            supervision_strategies: Arc::new(DashMap::new()),
+            watchers: Arc::new(DashMap::new()),
+            watched_targets: Arc::new(DashMap::new()),
             wakeup_manager,
+            thread_pool_manager,
             total_actor_count: Arc::new(AtomicUsize::new(0)),
             pool_actor_count: Arc::new(DashMap::new()),
             max_actors_per_pool,
             is_stopped: Arc::new(AtomicBool::new(false)),
             is_stopping: Arc::new(AtomicBool::new(false)),
+            is_shutdown_requested: Arc::new(AtomicBool::new(false)),
+            is_force_stop_requested: Arc::new(AtomicBool::new(false)),
+            force_stop_sender: Arc::new(Mutex::new(Some(force_stop_sender))),
+            force_stop_receiver,
+            is_signal_handling_registered: Arc::new(AtomicBool::new(false)),
+            shutdown_phases: Arc::new(DashMap::new()),
+            phase_actor_count: Arc::new(Mutex::new(BTreeMap::new())),
+            stopping_phase: Arc::new(AtomicUsize::new(0)),
+            force_stopped_actors: Arc::new(Mutex::new(Vec::new())),
+            state_changed: Arc::new((Mutex::new(()), Condvar::new())),
+            shutdown_hooks: Arc::new(Mutex::new(Vec::new())),
             is_force_stopped: Arc::new(AtomicBool::new(false)),
             forced_exit_code: Arc::new(AtomicI32::new(0)),
             use_forced_exit_code: Arc::new(AtomicBool::new(false)),
@@ -45,33 +95,281 @@ impl SystemState {
     }
 
     pub fn stop(&self, graceful_termination_timeout: Duration) {
-        if self.is_stopping() {
+        if self.is_shutdown_requested.swap(true, Ordering::SeqCst) {
             return;
         }
-        self.is_stopping.store(true, Ordering::Relaxed);
         let s = self.clone();
         std::thread::spawn(move || s.shutdown(graceful_termination_timeout));
     }
 
+    /// stops the system without waiting for any remaining actors or shutdown hooks
+    pub fn force_stop(&self) {
+        self.is_force_stop_requested.store(true, Ordering::SeqCst);
+        self.force_stop_sender.lock().unwrap().take();
+        self.stop(Duration::ZERO);
+        let (lock, condvar) = &*self.state_changed;
+        let _guard = lock.lock().unwrap();
+        condvar.notify_all();
+    }
+
+    /// returns `true` once [stop](#method.stop) was called
+    pub fn is_shutdown_requested(&self) -> bool {
+        return self.is_shutdown_requested.load(Ordering::SeqCst);
+    }
+
+    fn is_force_stop_requested(&self) -> bool {
+        return self.is_force_stop_requested.load(Ordering::SeqCst);
+    }
+
+    /// returns `false` if signal handling was already registered
+    pub fn register_signal_handling(&self) -> bool {
+        return !self
+            .is_signal_handling_registered
+            .swap(true, Ordering::SeqCst);
+    }
+
+    /// allows the signal handling to be registered again, i.e. if the registration failed
+    pub fn unregister_signal_handling(&self) {
+        self.is_signal_handling_registered
+            .store(false, Ordering::SeqCst);
+    }
+
+    /// each phase is limited by `timeout`
     fn shutdown(&self, timeout: Duration) {
-        let now = Instant::now();
-        while self.get_actor_count() != 0 {
-            if now.elapsed() >= timeout {
-                self.is_force_stopped.store(true, Ordering::Relaxed);
-                self.mailboxes.clear();
-                break;
+        self.run_shutdown_hooks(ShutdownPhase::BeforeActorStop, timeout);
+
+        while let Some(phase) = self.get_next_shutdown_phase() {
+            let phase = phase.max(self.stopping_phase.load(Ordering::SeqCst));
+            self.stopping_phase.store(phase, Ordering::SeqCst);
+            self.is_stopping.store(true, Ordering::SeqCst);
+            // inactive and sleeping actors need to be woken up to receive the `SystemStopMessage`
+            for mailbox in self.mailboxes.iter() {
+                if self.get_shutdown_phase(mailbox.key()) > phase {
+                    continue;
+                }
+                if mailbox.claim_wakeup() {
+                    self.wakeup_manager.wakeup(mailbox.key().clone());
+                } else {
+                    self.wakeup_manager.interrupt_sleep(mailbox.key());
+                }
+            }
+            let has_remaining = || match self.get_next_shutdown_phase() {
+                Some(next_phase) => next_phase <= phase,
+                None => false,
+            };
+            if !self.await_removed(has_remaining, timeout) {
+                self.force_stop_phase(phase);
+            }
+        }
+
+        self.run_shutdown_hooks(ShutdownPhase::AfterActorStop, timeout);
+
+        let (lock, condvar) = &*self.state_changed;
+        let _guard = lock.lock().unwrap();
+        self.is_stopped.store(true, Ordering::SeqCst);
+        condvar.notify_all();
+        self.thread_pool_manager.stop();
+    }
+
+    /// returns the lowest shutdown phase of all remaining actors
+    fn get_next_shutdown_phase(&self) -> Option<usize> {
+        return self
+            .phase_actor_count
+            .lock()
+            .unwrap()
+            .keys()
+            .next()
+            .copied();
+    }
+
+    /// returns `false` if `has_remaining` is still `true` after `timeout`
+    /// `has_remaining` is evaluated whenever an actor is removed and must therefore be cheap
+    fn await_removed<F>(&self, has_remaining: F, timeout: Duration) -> bool
+    where
+        F: Fn() -> bool,
+    {
+        let started = Instant::now();
+        let (lock, condvar) = &*self.state_changed;
+        let mut guard = lock.lock().unwrap();
+        while has_remaining() {
+            let remaining = timeout.saturating_sub(started.elapsed());
+            if remaining.is_zero() || self.is_force_stop_requested() {
+                return false;
+            }
+            guard = condvar.wait_timeout(guard, remaining).unwrap().0;
+        }
+        return true;
+    }
+
+    /// kills all remaining actors of the phase and removes them from the system without waiting for them
+    fn force_stop_phase(&self, phase: usize) {
+        let addresses: Vec<ActorAddress> = self
+            .mailboxes
+            .iter()
+            .map(|mailbox| mailbox.key().clone())
+            .filter(|address| self.get_shutdown_phase(address) <= phase)
+            .collect();
+        for address in addresses.iter() {
+            self.terminate_mailbox(address, true);
+            self.remove_mailbox(address, TerminationReason::Killed);
+            error!(
+                "Actor {} was force stopped in shutdown phase {}",
+                address, phase
+            );
+        }
+        self.is_force_stopped.store(true, Ordering::Relaxed);
+        self.force_stopped_actors.lock().unwrap().extend(addresses);
+    }
+
+    /// stops all actors of the pool and removes it, actors that did not stop within `timeout` are killed
+    pub fn remove_pool(&self, pool_name: &str, timeout: Duration) -> Result<(), ActorError> {
+        // prevents new actors from being spawned on the pool
+        if self.max_actors_per_pool.remove(pool_name).is_none() {
+            return Err(ActorError::ThreadPoolDoesNotExistError);
+        }
+        let started = Instant::now();
+        let is_in_pool = |address: &ActorAddress| address.pool == pool_name;
+        let addresses: Vec<ActorAddress> = self
+            .mailboxes
+            .iter()
+            .map(|mailbox| mailbox.key().clone())
+            .filter(is_in_pool)
+            .collect();
+        for address in addresses.iter() {
+            self.terminate_mailbox(address, false);
+        }
+
+        let has_remaining = || match self.pool_actor_count.get(pool_name) {
+            Some(count) => count.value().load(Ordering::Relaxed) > 0,
+            None => false,
+        };
+        if !self.await_removed(has_remaining, timeout) {
+            self.force_stop_pool(pool_name);
+        }
+
+        let remaining = timeout.saturating_sub(started.elapsed());
+        if !self.thread_pool_manager.remove_pool(pool_name, remaining) {
+            error!(
+                "Threads of pool {} did not return within the timeout",
+                pool_name
+            );
+        }
+        // actors that can no longer be scheduled, because their pool is gone
+        self.force_stop_pool(pool_name);
+        self.pool_actor_count.remove(pool_name);
+        return Ok(());
+    }
+
+    /// kills all remaining actors of the pool and removes them from the system without waiting for them
+    fn force_stop_pool(&self, pool_name: &str) {
+        let addresses: Vec<ActorAddress> = self
+            .mailboxes
+            .iter()
+            .map(|mailbox| mailbox.key().clone())
+            .filter(|address| address.pool == pool_name)
+            .collect();
+        for address in addresses.iter() {
+            self.terminate_mailbox(address, true);
+            self.remove_mailbox(address, TerminationReason::Killed);
+            error!(
+                "Actor {} was force stopped while removing pool {}",
+                address, pool_name
+            );
+        }
+    }
+
+    fn get_shutdown_phase(&self, address: &ActorAddress) -> usize {
+        return match self.shutdown_phases.get(address) {
+            Some(phase) => *phase.value(),
+            None => 0,
+        };
+    }
+
+    /// returns `true` if the system is stopping and has reached the given phase
+    pub fn is_phase_stopping(&self, phase: usize) -> bool {
+        return self.is_stopping() && phase <= self.stopping_phase.load(Ordering::SeqCst);
+    }
+
+    pub fn get_force_stopped_actors(&self) -> Vec<ActorAddress> {
+        return self.force_stopped_actors.lock().unwrap().clone();
+    }
+
+    /// blocks until the system is stopped
+    pub fn await_stopped(&self) {
+        let (lock, condvar) = &*self.state_changed;
+        let mut guard = lock.lock().unwrap();
+        while !self.is_stopped() {
+            guard = condvar.wait(guard).unwrap();
+        }
+    }
+
+    pub fn register_shutdown_hook(&self, phase: ShutdownPhase, hook: ShutdownHook) {
+        self.shutdown_hooks.lock().unwrap().push((phase, hook));
+    }
+
+    /// executes all hooks of the phase in the order they were registered, hooks that are still running after `timeout` are abandoned
+    fn run_shutdown_hooks(&self, phase: ShutdownPhase, timeout: Duration) {
+        let hooks: Vec<ShutdownHook> = {
+            let mut shutdown_hooks = self.shutdown_hooks.lock().unwrap();
+            let (hooks, remaining): (Vec<_>, Vec<_>) =
+                shutdown_hooks.drain(..).partition(|(p, _)| *p == phase);
+            *shutdown_hooks = remaining;
+            hooks.into_iter().map(|(_, hook)| hook).collect()
+        };
+        let started = Instant::now();
+        let total = hooks.len();
+        for (i, hook) in hooks.into_iter().enumerate() {
+            if self.is_force_stop_requested() {
+                error!(
+                    "Shutdown phase {:?} was force stopped, skipped {} remaining hooks",
+                    phase,
+                    total - i
+                );
+                return;
+            }
+            let (sender, receiver) = flume::bounded(1);
+            std::thread::spawn(move || {
+                hook();
+                let _ = sender.send(());
+            });
+            let remaining = timeout.saturating_sub(started.elapsed());
+            // a force stop disconnects `force_stop_receiver` and wakes up the wait immediately
+            let result = flume::Selector::new()
+                .recv(&receiver, Some)
+                .recv(&self.force_stop_receiver, |_| None)
+                .wait_timeout(remaining);
+            match result {
+                Ok(Some(Ok(()))) => continue,
+                Ok(Some(Err(_))) => {
+                    error!("Shutdown hook {} of phase {:?} panicked", i, phase);
+                }
+                Ok(None) => {
+                    error!(
+                        "Shutdown phase {:?} was force stopped, abandoned hook {} and skipped {} remaining hooks",
+                        phase,
+                        i,
+                        total - i - 1
+                    );
+                    return;
+                }
+                Err(_) => {
+                    error!(
+                        "Shutdown phase {:?} timed out, skipped {} remaining hooks",
+                        phase,
+                        total - i - 1
+                    );
+                    return;
+                }
             }
-            sleep(Duration::from_millis(10));
         }
-        self.is_stopped.store(true, Ordering::Relaxed);
     }
 
     pub fn is_stopped(&self) -> bool {
-        self.is_stopped.load(Ordering::Relaxed)
+        self.is_stopped.load(Ordering::SeqCst)
     }
 
     pub fn is_stopping(&self) -> bool {
-        self.is_stopping.load(Ordering::Relaxed)
+        self.is_stopping.load(Ordering::SeqCst)
     }
 
     pub fn use_forced_exit_code(&self, code: i32) {
@@ -94,41 +392,254 @@ impl SystemState {
         self.total_actor_count.load(Ordering::Relaxed)
     }
 
-    pub fn send_to_address(&self, address: &ActorAddress, msg: SerializedMessage) {
+    /// returns `false` if there's no actor with the given address
+    pub fn send_to_address(&self, address: &ActorAddress, msg: SerializedMessage) -> bool {
         let target = self.mailboxes.get(address);
-        if target.is_some() {
-            let target = target.unwrap();
-            target.send_serialized(msg);
-            if target.is_sleeping() {
-                self.wakeup_manager.wakeup(target.key().clone());
-            }
+        if target.is_none() {
+            return false;
         }
+        let target = target.unwrap();
+        target.send_serialized(msg);
+        if target.claim_wakeup() {
+            self.wakeup_manager.wakeup(target.key().clone());
+        }
+        return true;
     }
 
-    pub fn remove_mailbox(&self, address: &ActorAddress) {
+    pub fn remove_mailbox(&self, address: &ActorAddress, reason: TerminationReason) {
+        let mailbox = self.mailboxes.remove(address);
+        // the actor has already been removed, i.e. because it was force stopped
+        if mailbox.is_none() {
+            return;
+        }
         self.total_actor_count.fetch_sub(1, Ordering::Relaxed);
         self.pool_actor_count
             .entry(address.pool.clone())
             .and_modify(|v| {
                 v.fetch_sub(1, Ordering::Relaxed);
             });
-        self.mailboxes.remove(address);
+        self.supervision_strategies.remove(address);
+        if let Some((_, phase)) = self.shutdown_phases.remove(address) {
+            let mut phase_actor_count = self.phase_actor_count.lock().unwrap();
+            let count = phase_actor_count.entry(phase).or_insert(1);
+            *count -= 1;
+            if *count == 0 {
+                phase_actor_count.remove(&phase);
+            }
+        }
+        self.wakeup_manager.remove(address);
+
+        let parent = self.parents.remove(address);
+        if parent.is_some() {
+            let (_, parent) = parent.unwrap();
+            self.children
+                .entry(parent)
+                .and_modify(|v| v.retain(|child| child != address));
+        }
+
+        let children = self.children.remove(address);
+        if children.is_some() {
+            let (_, children) = children.unwrap();
+            for child in children.iter() {
+                self.terminate_mailbox(child, false);
+            }
+        }
+
+        let watchers = self.watchers.remove(address);
+        if watchers.is_some() {
+            let (_, watchers) = watchers.unwrap();
+            for watcher in watchers.iter() {
+                self.remove_watched_target(watcher, address);
+                self.send_to_mailbox(watcher, |mailbox| {
+                    mailbox.send_terminated(address.clone(), reason)
+                });
+            }
+        }
+        if let Some((_, watched_targets)) = self.watched_targets.remove(address) {
+            for target in watched_targets.iter() {
+                self.remove_watcher(target, address);
+            }
+        }
+
+        let (_, mailbox) = mailbox.unwrap();
+        mailbox.get_termination_handle().terminate(reason);
+
+        let (lock, condvar) = &*self.state_changed;
+        let _guard = lock.lock().unwrap();
+        condvar.notify_all();
+    }
+
+    /// tells the actor to stop and returns a handle that resolves once it was removed
+    pub fn stop_actor(&self, address: &ActorAddress) -> Result<TerminationHandle, ActorError> {
+        let termination_handle = match self.mailboxes.get(address) {
+            Some(mailbox) => mailbox.value().get_termination_handle(),
+            None => return Err(ActorError::ActorDoesNotExistError),
+        };
+        self.terminate_mailbox(address, false);
+        return Ok(termination_handle);
+    }
+
+    pub fn watch(&self, watcher: ActorAddress, target: ActorAddress) {
+        {
+            let mut watchers = self.watchers.entry(target.clone()).or_insert(Vec::new());
+            if !watchers.contains(&watcher) {
+                watchers.push(watcher.clone());
+            }
+        }
+        {
+            let mut targets = self
+                .watched_targets
+                .entry(watcher.clone())
+                .or_insert(Vec::new());
+            if !targets.contains(&target) {
+                targets.push(target.clone());
+            }
+        }
+        if self.is_mailbox_active(&target) {
+            return;
+        }
+        // the target terminated before it could be watched
+        let is_registered = self.remove_watcher(&target, &watcher);
+        self.remove_watched_target(&watcher, &target);
+        if is_registered {
+            self.send_to_mailbox(&watcher, |mailbox| {
+                mailbox.send_terminated(target.clone(), TerminationReason::Stopped)
+            });
+        }
+    }
+
+    pub fn unwatch(&self, watcher: &ActorAddress, target: &ActorAddress) {
+        self.remove_watcher(target, watcher);
+        self.remove_watched_target(watcher, target);
+    }
+
+    /// returns `true` if the `watcher` was registered for the `target`
+    fn remove_watcher(&self, target: &ActorAddress, watcher: &ActorAddress) -> bool {
+        let mut is_registered = false;
+        self.watchers.entry(target.clone()).and_modify(|v| {
+            is_registered = v.contains(watcher);
+            v.retain(|w| w != watcher);
+        });
+        self.watchers.remove_if(target, |_, v| v.is_empty());
+        return is_registered;
+    }
+
+    fn remove_watched_target(&self, watcher: &ActorAddress, target: &ActorAddress) {
+        self.watched_targets
+            .entry(watcher.clone())
+            .and_modify(|v| v.retain(|t| t != target));
+        self.watched_targets.remove_if(watcher, |_, v| v.is_empty());
+    }
+
+    pub fn set_supervision_strategy(&self, address: ActorAddress, strategy: SupervisionStrategy) {
+        self.supervision_strategies.insert(address, strategy);
+    }
+
+    pub fn add_child(&self, parent: ActorAddress, child: ActorAddress) {
+        self.parents.insert(child.clone(), parent.clone());
+        self.children
+            .entry(parent)
+            .or_insert(Vec::new())
+            .push(child);
+    }
+
+    pub fn get_parent(&self, address: &ActorAddress) -> Option<ActorAddress> {
+        return self
+            .parents
+            .get(address)
+            .map(|parent| parent.value().clone());
+    }
+
+    pub fn get_children(&self, address: &ActorAddress) -> Vec<ActorAddress> {
+        return match self.children.get(address) {
+            Some(children) => children.value().clone(),
+            None => Vec::new(),
+        };
+    }
+
+    /// returns the [SupervisionStrategy] of the parent, or `None` if the actor has no parent
+    pub fn get_parent_supervision_strategy(
+        &self,
+        address: &ActorAddress,
+    ) -> Option<SupervisionStrategy> {
+        let parent = self.get_parent(address)?;
+        let strategy = self.supervision_strategies.get(&parent)?;
+        return Some(*strategy.value());
+    }
+
+    /// restarts or kills all other children of the parent of the actor
+    pub fn supervise_siblings(&self, address: &ActorAddress, kill: bool) {
+        let parent = self.get_parent(address);
+        if parent.is_none() {
+            return;
+        }
+        for sibling in self.get_children(&parent.unwrap()).iter() {
+            if sibling == address {
+                continue;
+            }
+            if kill {
+                self.terminate_mailbox(sibling, true);
+            } else {
+                self.send_to_mailbox(sibling, |mailbox| mailbox.send_restart());
+            }
+        }
+    }
+
+    /// informs the parent of the actor about an escalated failure, returns `false` if the actor has no parent
+    pub fn escalate(&self, address: &ActorAddress) -> bool {
+        let parent = self.get_parent(address);
+        if parent.is_none() {
+            return false;
+        }
+        self.send_to_mailbox(&parent.unwrap(), |mailbox| {
+            mailbox.send_escalation(address.clone())
+        });
+        return true;
+    }
+
+    /// stops or kills the actor, a sleeping actor is woken up immediately
+    fn terminate_mailbox(&self, address: &ActorAddress, kill: bool) {
+        if !self.mailboxes.contains_key(address) {
+            return;
+        }
+        if kill {
+            self.send_to_mailbox(address, |mailbox| mailbox.send_kill());
+        } else {
+            self.send_to_mailbox(address, |mailbox| mailbox.send_stop());
+        }
+        self.wakeup_manager.interrupt_sleep(address);
+    }
+
+    fn send_to_mailbox<F>(&self, address: &ActorAddress, f: F)
+    where
+        F: Fn(&Arc<dyn BaseMailbox>),
+    {
+        let target = self.mailboxes.get(address);
+        if target.is_some() {
+            let target = target.unwrap();
+            f(target.value());
+            if target.claim_wakeup() {
+                self.wakeup_manager.wakeup(target.key().clone());
+            }
+        }
     }
 
     pub fn add_mailbox<A>(
         &self,
         address: ActorAddress,
+        shutdown_phase: usize,
         mailbox: Mailbox<A>,
     ) -> Result<(), ActorError>
     where
         A: Handler<SerializedMessage> + 'static,
     {
-        let maximum_actor_count = self.max_actors_per_pool.get(&address.pool);
-        if maximum_actor_count.is_none() {
+        // the guard is held until the mailbox was inserted, [remove_pool](#method.remove_pool) therefore either sees the mailbox or prevents the insertion
+        let pool_limit = self.max_actors_per_pool.get(&address.pool);
+        if pool_limit.is_none() {
             return Err(ActorError::ThreadPoolDoesNotExistError);
         }
-        let maximum_actor_count = maximum_actor_count.unwrap();
-        let maximum_actor_count = *maximum_actor_count.value();
+        let pool_limit = pool_limit.unwrap();
+        let maximum_actor_count = *pool_limit.value();
 
         let current_pool_count = self
             .pool_actor_count
@@ -143,7 +654,15 @@ impl SystemState {
 
         current_pool_count.fetch_add(1, Ordering::Relaxed);
         self.total_actor_count.fetch_add(1, Ordering::Relaxed);
+        self.shutdown_phases.insert(address.clone(), shutdown_phase);
+        *self
+            .phase_actor_count
+            .lock()
+            .unwrap()
+            .entry(shutdown_phase)
+            .or_insert(0) += 1;
         self.mailboxes.insert(address, Arc::new(mailbox));
+        drop(pool_limit);
         return Ok(());
     }
 
@@ -151,6 +670,21 @@ impl SystemState {
         self.max_actors_per_pool.insert(pool_name, max_actors);
     }
 
+    /// only updates existing pools, so that a pool that is being removed is not added again
+    pub fn update_pool_actor_limit(
+        &self,
+        pool_name: &str,
+        max_actors: usize,
+    ) -> Result<(), ActorError> {
+        return match self.max_actors_per_pool.get_mut(pool_name) {
+            Some(mut maximum_actor_count) => {
+                *maximum_actor_count = max_actors;
+                Ok(())
+            }
+            None => Err(ActorError::ThreadPoolDoesNotExistError),
+        };
+    }
+
     pub fn get_available_actor_count_for_pool(&self, pool_name: &str) -> Result<usize, ActorError> {
         let maximum_actor_count = self.max_actors_per_pool.get(pool_name);
         if maximum_actor_count.is_none() {
@@ -159,15 +693,19 @@ impl SystemState {
         let maximum_actor_count = maximum_actor_count.unwrap();
         let maximum_actor_count = *maximum_actor_count.value();
 
-        let current_pool_count = self.pool_actor_count.get(pool_name).unwrap();
-        let current_pool_count = current_pool_count.value().load(Ordering::Relaxed);
+        // the count is only created once the first actor was spawned on the pool
+        let current_pool_count = match self.pool_actor_count.get(pool_name) {
+            Some(count) => count.value().load(Ordering::Relaxed),
+            None => 0,
+        };
 
         if maximum_actor_count == 0 {
             let result = usize::MAX - current_pool_count;
             return Ok(result);
         }
 
-        let result = maximum_actor_count - current_pool_count;
+        // the actor_limit can be lowered below the current amount of actors at runtime
+        let result = maximum_actor_count.saturating_sub(current_pool_count);
         return Ok(result);
     }
 
@@ -179,7 +717,10 @@ impl SystemState {
     where
         A: Handler<SerializedMessage> + 'static,
     {
-        let mb = self.mailboxes.get(&address).unwrap().value().clone();
+        let mb = match self.mailboxes.get(&address) {
+            Some(mb) => mb.value().clone(),
+            None => return Err(ActorError::ActorDoesNotExistError),
+        };
         return match mb.as_any().downcast_ref::<Mailbox<A>>() {
             Some(m) => Ok(ActorWrapper::new(
                 m.clone(),
@@ -191,6 +732,15 @@ impl SystemState {
         };
     }
 
+    /// returns a snapshot of all actors that are currently registered
+    pub fn get_actor_infos(&self) -> Vec<ActorInfo> {
+        return self
+            .mailboxes
+            .iter()
+            .map(|mailbox| ActorInfo::new(mailbox.key().clone(), mailbox.value().get_state()))
+            .collect();
+    }
+
     pub fn is_mailbox_active(&self, address: &ActorAddress) -> bool {
         self.mailboxes.contains_key(address)
     }
@@ -1,136 +1,210 @@
 use crate::actor::actor_state::ActorState;
 use crate::actor::executor::ExecutorTrait;
 use crate::config::pool_config::ThreadPoolConfig;
+use crate::system::actor_error::ActorError;
 use crate::system::system_state::SystemState;
 use crate::system::wakeup_manager::WakeupManager;
-use crossbeam_channel::{bounded, unbounded, Receiver, Sender};
+use crossbeam_channel::{bounded, select, unbounded, Receiver, Sender};
 use dashmap::DashMap;
-use std::collections::HashMap;
-use std::sync::{Arc, RwLock};
-use std::thread::sleep;
+use std::sync::{Arc, Mutex, RwLock};
 use std::time::Duration;
 use threadpool::ThreadPool;
 
+struct ManagedThreadPool {
+    config: ThreadPoolConfig,
+    /// the amount of actors is limited by the SystemState, therefore the queue is unbounded and the actor_limit can be changed at runtime
+    sender: Sender<Arc<RwLock<dyn ExecutorTrait>>>,
+    receiver: Receiver<Arc<RwLock<dyn ExecutorTrait>>>,
+    pool: ThreadPool,
+    /// every message tells a single worker thread to return
+    shrink_sender: Sender<()>,
+    shrink_receiver: Receiver<()>,
+    thread_count: usize,
+}
+
 #[derive(Clone)]
 pub struct ThreadPoolManager {
-    thread_pools: Arc<
-        DashMap<
-            String,
-            (
-                ThreadPoolConfig,
-                Sender<Arc<RwLock<dyn ExecutorTrait>>>,
-                Receiver<Arc<RwLock<dyn ExecutorTrait>>>,
-            ),
-        >,
-    >,
+    thread_pools: Arc<DashMap<String, ManagedThreadPool>>,
+    /// dropped to signal all worker threads to return
+    stop_sender: Arc<Mutex<Option<Sender<()>>>>,
+    stop_receiver: Receiver<()>,
 }
 
 impl ThreadPoolManager {
     pub fn new() -> Self {
+        let (stop_sender, stop_receiver) = bounded(0);
         Self {
             thread_pools: Arc::new(DashMap::new()),
+            stop_sender: Arc::new(Mutex::new(Some(stop_sender))),
+            stop_receiver,
         }
     }
 
-    pub fn get_pool_sender(&self, name: &str) -> Sender<Arc<RwLock<dyn ExecutorTrait>>> {
-        let pool = self.thread_pools.get(name).unwrap();
-        let (_, sender, _) = pool.value().clone();
-        sender
+    /// returns `None` if the pool was removed
+    pub fn get_pool_sender(&self, name: &str) -> Option<Sender<Arc<RwLock<dyn ExecutorTrait>>>> {
+        return self
+            .thread_pools
+            .get(name)
+            .map(|pool| pool.value().sender.clone());
     }
 
-    pub fn add_pool_with_config(&self, name: &str, thread_pool_config: ThreadPoolConfig) {
-        if !self.thread_pools.contains_key(name) {
-            let (sender, receiver) = if thread_pool_config.actor_limit == 0 {
-                unbounded()
-            } else {
-                bounded(thread_pool_config.actor_limit)
-            };
-            self.thread_pools
-                .insert(String::from(name), (thread_pool_config, sender, receiver));
+    pub fn add_pool_with_config(
+        &self,
+        name: &str,
+        thread_pool_config: ThreadPoolConfig,
+        system_state: SystemState,
+        wakeup_manager: WakeupManager,
+    ) {
+        if self.thread_pools.contains_key(name) {
+            return;
         }
+        let (sender, receiver) = unbounded();
+        let (shrink_sender, shrink_receiver) = unbounded();
+        let thread_count = Self::get_thread_count(&thread_pool_config);
+        let pool = ManagedThreadPool {
+            config: thread_pool_config,
+            sender,
+            receiver,
+            pool: ThreadPool::with_name(String::from(name), thread_count),
+            shrink_sender,
+            shrink_receiver,
+            thread_count,
+        };
+        self.start_workers(&pool, thread_count, system_state, wakeup_manager);
+        self.thread_pools.insert(String::from(name), pool);
     }
 
-    pub fn manage(&self, system_state: SystemState, wakeup_manager: WakeupManager) {
-        let mut pools: HashMap<String, ThreadPool> = HashMap::new();
-        loop {
-            let is_stopped = system_state.is_stopped();
-            if is_stopped {
-                for pool in pools.iter() {
-                    pool.1.join()
-                }
-                return;
+    /// resizes the thread_pool, surplus worker threads return as soon as they finished their current actor
+    pub fn update_pool_config(
+        &self,
+        name: &str,
+        thread_pool_config: ThreadPoolConfig,
+        system_state: SystemState,
+        wakeup_manager: WakeupManager,
+    ) -> Result<(), ActorError> {
+        let pool = self.thread_pools.get_mut(name);
+        if pool.is_none() {
+            return Err(ActorError::ThreadPoolDoesNotExistError);
+        }
+        let mut pool = pool.unwrap();
+
+        let thread_count = Self::get_thread_count(&thread_pool_config);
+        if thread_count > pool.thread_count {
+            pool.pool.set_num_threads(thread_count);
+            let amount = thread_count - pool.thread_count;
+            self.start_workers(&pool, amount, system_state, wakeup_manager);
+        } else if thread_count < pool.thread_count {
+            for _i in thread_count..pool.thread_count {
+                let _ = pool.shrink_sender.send(());
             }
-            for pool in self.thread_pools.iter() {
-                let pool_name = pool.key().clone();
-                let (pool_config, pool_sender, pool_receiver) = pool.value().clone();
-                if !pools.contains_key(&pool_name) {
-                    let thread_count = pool_config.threads_factor * num_cpus::get() as f32;
-                    let mut thread_count = thread_count.floor() as usize;
-                    if thread_count < pool_config.threads_min {
-                        thread_count = pool_config.threads_min;
-                    } else if thread_count > pool_config.threads_max {
-                        thread_count = pool_config.threads_max;
-                    }
+            pool.pool.set_num_threads(thread_count);
+        }
+        pool.thread_count = thread_count;
+        pool.config = thread_pool_config;
+        return Ok(());
+    }
 
-                    pools.insert(
-                        pool_name.clone(),
-                        ThreadPool::with_name(pool_name.clone(), thread_count),
-                    );
-                }
-                let current = pools.get(&pool_name).unwrap();
-                for _i in current.active_count()..current.max_count() {
-                    let sender = pool_sender.clone();
-                    let receiver = pool_receiver.clone();
-                    let pool_name = pool_name.clone();
-                    let recv_timeout = Duration::from_secs(1);
-                    let system_state = system_state.clone();
-                    let wakeup_manager = wakeup_manager.clone();
-                    pools.get(&pool_name).unwrap().execute(move || loop {
-                        let is_system_stopping = system_state.is_stopping();
-                        let mut actor_state = ActorState::Running;
-                        let msg = receiver.recv_timeout(recv_timeout);
-                        if msg.is_err() {
-                            if system_state.is_stopped() {
-                                return;
-                            }
-                            continue;
-                        }
-                        let ar = msg.unwrap();
-                        {
-                            let mut actor_ref = ar.write().unwrap();
-                            let actor_config = actor_ref.get_config();
-                            for _j in 0..actor_config.message_throughput {
-                                actor_state = actor_ref.handle(is_system_stopping);
-                                if actor_state != ActorState::Running {
-                                    break;
-                                }
-                            }
-                        };
+    /// tells all worker threads of the pool to return and returns `false` if they did not return within `timeout`
+    pub fn remove_pool(&self, name: &str, timeout: Duration) -> bool {
+        let pool = self.thread_pools.remove(name);
+        if pool.is_none() {
+            return true;
+        }
+        let (_, pool) = pool.unwrap();
+        for _i in 0..pool.thread_count {
+            let _ = pool.shrink_sender.send(());
+        }
 
-                        if actor_state == ActorState::Running {
-                            sender.send(ar).unwrap();
-                        } else {
-                            let address;
-                            {
-                                let actor_ref = ar.write().unwrap();
-                                address = actor_ref.get_address();
-                            }
-                            match actor_state {
-                                ActorState::Inactive => {
-                                    wakeup_manager.add_inactive_actor(address, ar);
-                                }
-                                ActorState::Sleeping(duration) => {
-                                    wakeup_manager.add_sleeping_actor(address, ar, duration)
-                                }
-                                _ => {
-                                    system_state.remove_mailbox(&address);
-                                }
-                            }
+        let (sender, receiver) = flume::bounded(1);
+        std::thread::spawn(move || {
+            pool.pool.join();
+            let _ = sender.send(());
+        });
+        return receiver.recv_timeout(timeout).is_ok();
+    }
+
+    pub fn get_pool_config(&self, name: &str) -> Option<ThreadPoolConfig> {
+        return self
+            .thread_pools
+            .get(name)
+            .map(|pool| pool.value().config.clone());
+    }
+
+    fn get_thread_count(thread_pool_config: &ThreadPoolConfig) -> usize {
+        let thread_count = thread_pool_config.threads_factor * num_cpus::get() as f32;
+        let mut thread_count = thread_count.floor() as usize;
+        if thread_count < thread_pool_config.threads_min {
+            thread_count = thread_pool_config.threads_min;
+        } else if thread_count > thread_pool_config.threads_max {
+            thread_count = thread_pool_config.threads_max;
+        }
+        // every pool needs at least a single thread to make progress
+        return thread_count.max(1);
+    }
+
+    fn start_workers(
+        &self,
+        pool: &ManagedThreadPool,
+        amount: usize,
+        system_state: SystemState,
+        wakeup_manager: WakeupManager,
+    ) {
+        for _i in 0..amount {
+            let sender = pool.sender.clone();
+            let receiver = pool.receiver.clone();
+            let shrink_receiver = pool.shrink_receiver.clone();
+            let stop_receiver = self.stop_receiver.clone();
+            let system_state = system_state.clone();
+            let wakeup_manager = wakeup_manager.clone();
+            pool.pool.execute(move || loop {
+                let ar = select! {
+                    recv(receiver) -> msg => msg.unwrap(),
+                    recv(shrink_receiver) -> _ => return,
+                    recv(stop_receiver) -> _ => return,
+                };
+                let mut actor_state = ActorState::Running;
+                {
+                    let mut actor_ref = ar.write().unwrap();
+                    actor_ref.apply_config_updates();
+                    let actor_config = actor_ref.get_config();
+                    let is_system_stopping =
+                        system_state.is_phase_stopping(actor_config.shutdown_phase);
+                    for _j in 0..actor_config.message_throughput {
+                        actor_state = actor_ref.handle(is_system_stopping);
+                        if actor_state != ActorState::Running {
+                            break;
                         }
-                    });
+                    }
+                };
+
+                if actor_state == ActorState::Running {
+                    sender.send(ar).unwrap();
+                    continue;
                 }
-            }
-            sleep(Duration::from_millis(1000));
+                let address;
+                let termination_reason;
+                {
+                    let actor_ref = ar.write().unwrap();
+                    address = actor_ref.get_address();
+                    termination_reason = actor_ref.get_termination_reason();
+                }
+                match actor_state {
+                    ActorState::Inactive => {
+                        wakeup_manager.add_inactive_actor(address, ar);
+                    }
+                    ActorState::Sleeping(duration) => {
+                        wakeup_manager.add_sleeping_actor(address, ar, duration)
+                    }
+                    _ => {
+                        system_state.remove_mailbox(&address, termination_reason);
+                    }
+                }
+            });
         }
     }
+
+    /// signals all worker threads to return
+    pub fn stop(&self) {
+        self.stop_sender.lock().unwrap().take();
+    }
 }
@@ -0,0 +1,26 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+/// Returned by [ActorWrapper.send_after](../prelude/struct.ActorWrapper.html#method.send_after) and [ActorWrapper.send_interval](../prelude/struct.ActorWrapper.html#method.send_interval) to cancel a scheduled message
+#[derive(Clone)]
+pub struct TimerHandle {
+    is_active: Arc<AtomicBool>,
+}
+
+impl TimerHandle {
+    pub(crate) fn new() -> Self {
+        Self {
+            is_active: Arc::new(AtomicBool::new(true)),
+        }
+    }
+
+    /// Cancels the timer, messages that have not yet been sent are dropped
+    pub fn cancel(&self) {
+        self.is_active.store(false, Ordering::Relaxed);
+    }
+
+    /// Returns `false` if the timer has been cancelled or a delayed message has already been sent
+    pub fn is_active(&self) -> bool {
+        return self.is_active.load(Ordering::Relaxed);
+    }
+}
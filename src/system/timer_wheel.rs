@@ -0,0 +1,236 @@
+use crate::system::system_state::SystemState;
+use crate::system::timer_handle::TimerHandle;
+use crossbeam_channel::{unbounded, Receiver, RecvTimeoutError, Sender};
+use std::time::{Duration, Instant};
+
+/// each level of the wheel consists of 2^WHEEL_BITS slots
+const WHEEL_BITS: u32 = 6;
+const WHEEL_SIZE: usize = 1 << WHEEL_BITS;
+const WHEEL_MASK: u64 = (WHEEL_SIZE - 1) as u64;
+/// 6 levels with 64 slots and a tick of 1ms cover a range of ~2.2 years
+/// timers that are scheduled further in the future are re-inserted once they reach the last level
+const WHEEL_LEVELS: usize = 6;
+const WHEEL_RANGE: u64 = 1 << (WHEEL_BITS * WHEEL_LEVELS as u32);
+const TICK_NANOS: u128 = 1_000_000;
+const IDLE_TIMEOUT: Duration = Duration::from_millis(1000);
+/// limits the amount of new timers that are inserted between two ticks, so that bursts don't delay due timers
+const MAX_INSERTS_PER_TICK: usize = 4096;
+
+pub struct TimerEntry {
+    deadline: u64,
+    period: Option<u64>,
+    timer: TimerHandle,
+    task: Box<dyn FnMut() -> bool + Send>,
+}
+
+/// Hierarchical timing wheel that executes all timers of the system on a single thread
+///
+/// Inserting a timer is O(1), it's only sent to the wheel thread, which sorts it into the matching slot
+/// Timers on higher levels are cascaded down into the lower levels once their slot is reached
+/// Cancelled timers are not removed from the wheel, they are dropped once their slot is reached
+#[derive(Clone)]
+pub struct TimerWheel {
+    started: Instant,
+    timer_queue_in: Sender<TimerEntry>,
+    timer_queue_out: Receiver<TimerEntry>,
+}
+
+struct Wheel {
+    levels: Vec<Vec<Vec<TimerEntry>>>,
+    current: u64,
+    len: usize,
+}
+
+impl TimerWheel {
+    pub fn new() -> Self {
+        let (timer_queue_in, timer_queue_out) = unbounded();
+        Self {
+            started: Instant::now(),
+            timer_queue_in,
+            timer_queue_out,
+        }
+    }
+
+    /// executes `task` once after `delay`
+    /// `task` returns `false` if it could not be executed successfully
+    pub fn schedule_once<F>(&self, task: F, delay: Duration) -> TimerHandle
+    where
+        F: FnMut() -> bool + Send + 'static,
+    {
+        return self.schedule(Box::new(task), delay, None);
+    }
+
+    /// executes `task` every `period`, until the returned [TimerHandle](../prelude/struct.TimerHandle.html) is cancelled or `task` returns `false`
+    pub fn schedule_interval<F>(&self, task: F, period: Duration) -> TimerHandle
+    where
+        F: FnMut() -> bool + Send + 'static,
+    {
+        let ticks = Self::to_ticks(period).max(1);
+        return self.schedule(Box::new(task), period, Some(ticks));
+    }
+
+    fn schedule(
+        &self,
+        task: Box<dyn FnMut() -> bool + Send>,
+        delay: Duration,
+        period: Option<u64>,
+    ) -> TimerHandle {
+        let timer = TimerHandle::new();
+        let deadline = Self::to_ticks(self.started.elapsed() + delay);
+        let result = self.timer_queue_in.send(TimerEntry {
+            deadline,
+            period,
+            timer: timer.clone(),
+            task,
+        });
+        if result.is_err() {
+            timer.cancel();
+        }
+        return timer;
+    }
+
+    /// rounds up, so that a timer is never executed before its delay has passed
+    fn to_ticks(duration: Duration) -> u64 {
+        return ((duration.as_nanos() + TICK_NANOS - 1) / TICK_NANOS) as u64;
+    }
+
+    fn get_current_tick(&self) -> u64 {
+        return (self.started.elapsed().as_nanos() / TICK_NANOS) as u64;
+    }
+
+    pub fn manage(&self, system_state: SystemState) {
+        let mut wheel = Wheel::new();
+        loop {
+            if system_state.is_stopped() {
+                return;
+            }
+
+            let now = self.get_current_tick();
+            wheel.advance(now);
+
+            let recv_timeout = if wheel.is_empty() {
+                IDLE_TIMEOUT
+            } else {
+                let next_tick = self.started
+                    + Duration::from_nanos((wheel.get_next_tick() as u128 * TICK_NANOS) as u64);
+                next_tick.saturating_duration_since(Instant::now())
+            };
+            match self.timer_queue_out.recv_timeout(recv_timeout) {
+                Ok(entry) => {
+                    wheel.insert(entry);
+                    for entry in self.timer_queue_out.try_iter().take(MAX_INSERTS_PER_TICK) {
+                        wheel.insert(entry);
+                    }
+                }
+                Err(RecvTimeoutError::Timeout) => continue,
+                Err(RecvTimeoutError::Disconnected) => return,
+            }
+        }
+    }
+}
+
+impl Wheel {
+    fn new() -> Self {
+        let mut levels = Vec::with_capacity(WHEEL_LEVELS);
+        for _ in 0..WHEEL_LEVELS {
+            let mut slots = Vec::with_capacity(WHEEL_SIZE);
+            slots.resize_with(WHEEL_SIZE, Vec::new);
+            levels.push(slots);
+        }
+        Self {
+            levels,
+            current: 0,
+            len: 0,
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        return self.len == 0;
+    }
+
+    fn insert(&mut self, mut entry: TimerEntry) {
+        if entry.deadline < self.current {
+            entry.deadline = self.current;
+        }
+        let mut delta = entry.deadline - self.current;
+        let mut slot_deadline = entry.deadline;
+        if delta >= WHEEL_RANGE {
+            delta = WHEEL_RANGE - 1;
+            slot_deadline = self.current + delta;
+        }
+
+        let mut level = 0;
+        while level < WHEEL_LEVELS - 1 && delta >= 1 << (WHEEL_BITS * (level as u32 + 1)) {
+            level += 1;
+        }
+        let slot = ((slot_deadline >> (WHEEL_BITS * level as u32)) & WHEEL_MASK) as usize;
+        self.levels[level][slot].push(entry);
+        self.len += 1;
+    }
+
+    /// returns the next tick that either has timers on the lowest level, or cascades the higher levels
+    fn get_next_tick(&self) -> u64 {
+        let next_cascade = (self.current | WHEEL_MASK) + 1;
+        let mut tick = self.current;
+        while tick < next_cascade {
+            if !self.levels[0][(tick & WHEEL_MASK) as usize].is_empty() {
+                return tick;
+            }
+            tick += 1;
+        }
+        return next_cascade;
+    }
+
+    /// processes all ticks up to and including `now`
+    fn advance(&mut self, now: u64) {
+        if self.is_empty() {
+            self.current = self.current.max(now + 1);
+            return;
+        }
+        while self.current <= now {
+            self.tick();
+        }
+    }
+
+    fn tick(&mut self) {
+        let tick = self.current;
+        let mut level = 1;
+        while level < WHEEL_LEVELS {
+            let shift = WHEEL_BITS * level as u32;
+            // only cascade once all lower levels have completed a full rotation
+            if tick & ((1 << shift) - 1) != 0 {
+                break;
+            }
+            let slot = ((tick >> shift) & WHEEL_MASK) as usize;
+            let entries = std::mem::take(&mut self.levels[level][slot]);
+            self.len -= entries.len();
+            for entry in entries {
+                self.insert(entry);
+            }
+            level += 1;
+        }
+
+        let slot = (tick & WHEEL_MASK) as usize;
+        let entries = std::mem::take(&mut self.levels[0][slot]);
+        self.len -= entries.len();
+        self.current += 1;
+
+        for mut entry in entries {
+            if !entry.timer.is_active() {
+                continue;
+            }
+            if entry.deadline > tick {
+                self.insert(entry);
+                continue;
+            }
+            let success = (entry.task)();
+            match entry.period {
+                Some(period) if success => {
+                    entry.deadline += period;
+                    self.insert(entry);
+                }
+                _ => entry.timer.cancel(),
+            }
+        }
+    }
+}
@@ -1,183 +1,159 @@
 use crate::actor::actor_address::ActorAddress;
 use crate::actor::executor::ExecutorTrait;
-use crate::system::system_state::SystemState;
 use crate::system::thread_pool_manager::ThreadPoolManager;
-use crossbeam_channel::{unbounded, Receiver, Sender};
+use crate::system::timer_handle::TimerHandle;
+use crate::system::timer_wheel::TimerWheel;
+use dashmap::mapref::entry::Entry;
 use dashmap::DashMap;
-use std::collections::HashMap;
 use std::sync::{Arc, RwLock};
-use std::thread::sleep;
 use std::time::{Duration, Instant};
 
-pub struct Wakeup {
-    pub iteration: usize,
-    pub actor_address: ActorAddress,
+enum ParkedActor {
+    Executor(Arc<RwLock<dyn ExecutorTrait>>),
+    /// the actor was woken up before it could be parked
+    WakeupPending,
 }
 
-pub struct Sleep {
-    pub now: Instant,
-    pub duration: Duration,
-    pub actor_address: ActorAddress,
+enum SleepingActor {
+    Executor(Arc<RwLock<dyn ExecutorTrait>>, TimerHandle),
+    /// the sleep was interrupted before the actor was added
+    Interrupted,
 }
 
+/// Parks inactive actors and reschedules them onto their thread_pool as soon as they are woken up
+///
+/// An actor is only woken up by whoever switches its mailbox from sleeping to awake,
+/// which guarantees that every parked actor is rescheduled exactly once
 #[derive(Clone)]
 pub struct WakeupManager {
-    inactive_actors: Arc<DashMap<ActorAddress, Arc<RwLock<dyn ExecutorTrait>>>>,
-    wakeup_queue_in: Sender<Wakeup>,
-    wakeup_queue_out: Receiver<Wakeup>,
-    sleep_queue_in: Sender<Sleep>,
-    sleep_queue_out: Receiver<Sleep>,
+    parked_actors: Arc<DashMap<ActorAddress, ParkedActor>>,
+    sleeping_actors: Arc<DashMap<ActorAddress, SleepingActor>>,
+    thread_pool_manager: ThreadPoolManager,
+    timer_wheel: TimerWheel,
 }
 
 impl WakeupManager {
-    pub fn new() -> Self {
-        let (wakeup_queue_in, wakeup_queue_out) = unbounded();
-        let (sleep_queue_in, sleep_queue_out) = unbounded();
-
+    pub fn new(thread_pool_manager: ThreadPoolManager, timer_wheel: TimerWheel) -> Self {
         Self {
-            inactive_actors: Arc::new(DashMap::new()),
-            wakeup_queue_in,
-            wakeup_queue_out,
-            sleep_queue_in,
-            sleep_queue_out,
+            parked_actors: Arc::new(DashMap::new()),
+            sleeping_actors: Arc::new(DashMap::new()),
+            thread_pool_manager,
+            timer_wheel,
         }
     }
 
+    /// reschedules the actor once the sleep duration has passed, or immediately if the sleep has already been interrupted
     pub fn add_sleeping_actor(
         &self,
         address: ActorAddress,
         actor: Arc<RwLock<dyn ExecutorTrait>>,
         sleep: Duration,
     ) {
-        self.add_inactive_actor(address.clone(), actor);
-        self.sleep_queue_in
-            .send(Sleep {
-                now: Instant::now(),
-                duration: sleep,
-                actor_address: address,
-            })
-            .unwrap();
+        match self.sleeping_actors.entry(address.clone()) {
+            Entry::Occupied(entry) => {
+                entry.remove();
+            }
+            Entry::Vacant(entry) => {
+                actor
+                    .read()
+                    .unwrap()
+                    .set_sleeping_until(Some(Instant::now() + sleep));
+                let wakeup_manager = self.clone();
+                let timer_address = address.clone();
+                // the entry is locked until the timer was added, the timer can therefore never miss it
+                let timer = self.timer_wheel.schedule_once(
+                    move || {
+                        wakeup_manager.end_sleep(&timer_address);
+                        return true;
+                    },
+                    sleep,
+                );
+                entry.insert(SleepingActor::Executor(actor, timer));
+                return;
+            }
+        }
+        self.schedule(&address.pool, actor);
     }
 
-    pub fn add_inactive_actor(&self, address: ActorAddress, actor: Arc<RwLock<dyn ExecutorTrait>>) {
-        self.inactive_actors.insert(address, actor);
+    /// ends the sleep of the actor immediately, i.e. because it has to stop
+    /// if the actor is about to go to sleep, the sleep ends as soon as it starts
+    pub fn interrupt_sleep(&self, address: &ActorAddress) {
+        let sleeping_actor = match self.sleeping_actors.entry(address.clone()) {
+            Entry::Occupied(entry) => match entry.get() {
+                SleepingActor::Interrupted => return,
+                SleepingActor::Executor(_, _) => entry.remove(),
+            },
+            Entry::Vacant(entry) => {
+                entry.insert(SleepingActor::Interrupted);
+                return;
+            }
+        };
+        if let SleepingActor::Executor(actor, timer) = sleeping_actor {
+            timer.cancel();
+            actor.read().unwrap().set_sleeping_until(None);
+            self.schedule(&address.pool, actor);
+        }
     }
 
-    pub fn wakeup(&self, address: ActorAddress) {
-        self.wakeup_queue_in
-            .send(Wakeup {
-                actor_address: address,
-                iteration: 0,
-            })
-            .unwrap();
+    /// skips actors that were already woken up
+    fn end_sleep(&self, address: &ActorAddress) {
+        let sleeping_actor = self
+            .sleeping_actors
+            .remove_if(address, |_, sleeping_actor| {
+                matches!(sleeping_actor, SleepingActor::Executor(_, _))
+            });
+        if let Some((_, SleepingActor::Executor(actor, _))) = sleeping_actor {
+            actor.read().unwrap().set_sleeping_until(None);
+            // the run queue of the pool is unbounded, therefore rescheduling never blocks the timer thread
+            self.schedule(&address.pool, actor);
+        }
     }
 
-    pub fn manage_sleeping(&self, system_status: SystemState) {
-        let recv_timeout = Duration::from_millis(1000);
-        loop {
-            if system_status.is_stopped() || system_status.is_stopping() {
-                return;
-            }
-
-            let msg = self.sleep_queue_out.recv_timeout(recv_timeout);
-            if msg.is_err() {
-                continue;
+    /// parks the actor until it's woken up, or reschedules it immediately if it has already been woken up
+    pub fn add_inactive_actor(&self, address: ActorAddress, actor: Arc<RwLock<dyn ExecutorTrait>>) {
+        match self.parked_actors.entry(address.clone()) {
+            Entry::Occupied(entry) => {
+                entry.remove();
             }
-            let sleep_msg = msg.unwrap();
-            let duration = sleep_msg.now.elapsed();
-            if duration >= sleep_msg.duration {
-                self.wakeup_queue_in
-                    .send(Wakeup {
-                        iteration: 1,
-                        actor_address: sleep_msg.actor_address,
-                    })
-                    .unwrap();
-                continue;
+            Entry::Vacant(entry) => {
+                entry.insert(ParkedActor::Executor(actor));
+                return;
             }
-
-            sleep(Duration::from_millis(1000));
-            self.sleep_queue_in.send(sleep_msg).unwrap();
-            continue;
         }
+        self.schedule(&address.pool, actor);
     }
 
-    pub fn manage_inactive(
-        &self,
-        system_status: SystemState,
-        thread_pool_manager: ThreadPoolManager,
-    ) {
-        let mut wake_deduplication: HashMap<ActorAddress, Instant> = HashMap::new();
-        let recv_timeout = Duration::from_secs(1);
-        loop {
-            if system_status.is_stopped() {
+    /// must only be called by whoever switched the mailbox of the actor from sleeping to awake
+    pub fn wakeup(&self, address: ActorAddress) {
+        let parked_actor = match self.parked_actors.entry(address.clone()) {
+            Entry::Occupied(entry) => match entry.get() {
+                ParkedActor::WakeupPending => return,
+                ParkedActor::Executor(_) => entry.remove(),
+            },
+            Entry::Vacant(entry) => {
+                entry.insert(ParkedActor::WakeupPending);
                 return;
             }
-            if system_status.is_stopping() {
-                let mut keys: Vec<ActorAddress> = Vec::new();
-                for key in self.inactive_actors.iter() {
-                    keys.push(key.key().clone());
-                }
-                for key in keys {
-                    let inactive_actor = self.inactive_actors.remove(&key).unwrap();
-                    let pool_name = inactive_actor.0.pool;
-                    let actor_ref = inactive_actor.1;
-                    {
-                        let mut actor_ref = actor_ref.write().unwrap();
-                        actor_ref.wakeup();
-                    }
-                    let sender = thread_pool_manager.get_pool_sender(&pool_name);
-                    sender.send(actor_ref).unwrap();
-                }
-                continue;
-            }
-
-            let msg = self.wakeup_queue_out.recv_timeout(recv_timeout);
-            if msg.is_err() {
-                continue;
-            }
-            let wakeup_message = msg.unwrap();
-
-            if wake_deduplication.contains_key(&wakeup_message.actor_address)
-                && wakeup_message.iteration == 0
-            {
-                // actors have a minimum uptime of 1 second
-                // this ensures a guaranteed de-duplication of all wakeup calls to a single actor
-                let last_wakeup = wake_deduplication
-                    .get(&wakeup_message.actor_address)
-                    .unwrap();
-                let duration = last_wakeup.elapsed();
-                if duration >= Duration::from_millis(4000) {
-                    wake_deduplication.remove(&wakeup_message.actor_address);
-                } else {
-                    continue;
-                }
-            }
+        };
+        match parked_actor {
+            ParkedActor::Executor(actor) => self.schedule(&address.pool, actor),
+            ParkedActor::WakeupPending => {}
+        }
+    }
 
-            wake_deduplication.insert(wakeup_message.actor_address.clone(), Instant::now());
-            if !self
-                .inactive_actors
-                .contains_key(&wakeup_message.actor_address)
-            {
-                self.wakeup_queue_in
-                    .send(Wakeup {
-                        iteration: (wakeup_message.iteration + 1),
-                        actor_address: wakeup_message.actor_address,
-                    })
-                    .unwrap();
-                continue;
-            }
+    /// removes the remaining entries of a stopped actor, i.e. a wakeup that happened while it was stopping
+    pub fn remove(&self, address: &ActorAddress) {
+        self.parked_actors.remove(address);
+        let sleeping_actor = self.sleeping_actors.remove(address);
+        if let Some((_, SleepingActor::Executor(_, timer))) = sleeping_actor {
+            timer.cancel();
+        }
+    }
 
-            let actor_ref = self
-                .inactive_actors
-                .remove(&wakeup_message.actor_address)
-                .unwrap()
-                .1;
-            {
-                let mut actor_ref = actor_ref.write().unwrap();
-                actor_ref.wakeup();
-            }
-            let sender = thread_pool_manager.get_pool_sender(&wakeup_message.actor_address.pool);
-            sender.send(actor_ref).unwrap();
+    /// actors of removed pools are dropped
+    fn schedule(&self, pool_name: &str, actor: Arc<RwLock<dyn ExecutorTrait>>) {
+        if let Some(sender) = self.thread_pool_manager.get_pool_sender(pool_name) {
+            let _ = sender.send(actor);
         }
     }
 }